@@ -0,0 +1,174 @@
+//! Benchmarks for the request hot path: URL assembly, response
+//! deserialization, rate limiter slot acquisition, and pagination cursor
+//! parsing. Run with `cargo bench`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use torn_client::{IpRateLimiter, RateLimitMode};
+
+/// Mirrors the client's URL assembly: base + path, then query pairs.
+fn bench_url_building(c: &mut Criterion) {
+    let base = "https://api.torn.com/v2";
+    let query: Vec<(String, String)> = vec![
+        ("from".into(), "1700000000".into()),
+        ("to".into(), "1700600000".into()),
+        ("comment".into(), "bench".into()),
+    ];
+    c.bench_function("url_building", |b| {
+        b.iter(|| {
+            let mut url = url::Url::parse(&format!("{base}/faction/attacks")).unwrap();
+            url.query_pairs_mut()
+                .extend_pairs(query.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+            black_box(url.to_string())
+        })
+    });
+}
+
+/// A large `/faction/attacks` page, the biggest payload the client routinely
+/// decodes.
+fn attacks_fixture(count: usize) -> String {
+    let attack = r#"{
+        "id": 123456789, "code": "abc123def456", "started": 1700000000,
+        "ended": 1700000035,
+        "attacker": {"id": 1, "name": "A", "level": 50,
+                     "faction": {"id": 2, "name": "F"}},
+        "defender": {"id": 3, "name": "B", "level": 60, "faction": null},
+        "result": "Hospitalized", "respect_gain": 4.82, "respect_loss": 0.0,
+        "chain": 250, "is_stealthed": false, "is_raid": false,
+        "is_ranked_war": true,
+        "modifiers": {"fair_fight": 1.4, "war": 2.0, "retaliation": 1.0,
+                      "group": 1.0, "overseas": 1.0, "chain": 1.2}
+    }"#;
+    let attacks = std::iter::repeat_n(attack, count)
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("{{\"attacks\":[{attacks}],\"_metadata\":{{\"links\":{{\"next\":\"https://api.torn.com/v2/faction/attacks?limit=100&to=1699999999\",\"prev\":null}}}}}}")
+}
+
+fn bench_deserialization(c: &mut Criterion) {
+    #[derive(serde::Deserialize)]
+    struct Page {
+        attacks: Vec<torn_client::models::user::Attack>,
+    }
+
+    let mut group = c.benchmark_group("deserialize_attacks_page");
+    for count in [100, 1000] {
+        let json = attacks_fixture(count);
+        group.bench_with_input(BenchmarkId::from_parameter(count), &json, |b, json| {
+            b.iter(|| {
+                let page: Page = serde_json::from_slice(black_box(json.as_bytes())).unwrap();
+                black_box(page.attacks.len())
+            })
+        });
+    }
+    group.finish();
+}
+
+/// Many tasks racing for slots on one shared limiter, far below the cap so
+/// the cost measured is bookkeeping, not intentional sleeping.
+fn bench_limiter_contention(c: &mut Criterion) {
+    let rt = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(4)
+        .enable_time()
+        .build()
+        .unwrap();
+    c.bench_function("ip_limiter_acquire_contended", |b| {
+        b.to_async(&rt).iter(|| async {
+            let limiter = std::sync::Arc::new(IpRateLimiter::with_limit(u32::MAX));
+            let tasks: Vec<_> = (0..32)
+                .map(|_| {
+                    let limiter = limiter.clone();
+                    tokio::spawn(async move { limiter.acquire(RateLimitMode::Error).await })
+                })
+                .collect();
+            for task in tasks {
+                black_box(task.await.unwrap());
+            }
+        })
+    });
+}
+
+/// A limiter whose window is already full: every acquire lands on the
+/// saturated path, where the old `Vec<Instant>` bookkeeping re-scanned the
+/// full window under the lock and the per-second counters do an O(1) check.
+fn bench_limiter_saturated(c: &mut Criterion) {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap();
+    let limiter = IpRateLimiter::with_limit(1000);
+    rt.block_on(async {
+        for _ in 0..1000 {
+            assert!(limiter.acquire(RateLimitMode::Error).await);
+        }
+    });
+    c.bench_function("ip_limiter_acquire_saturated", |b| {
+        b.to_async(&rt)
+            .iter(|| async { black_box(limiter.acquire(RateLimitMode::Error).await) })
+    });
+}
+
+/// Decoding a `/faction/attacks` page from the wire with and without gzip,
+/// with throughput set to the respective wire sizes: the compressed fixture
+/// is roughly a tenth of the plain one, which is the bandwidth the
+/// `compression` feature saves per page.
+fn bench_compressed_page_decode(c: &mut Criterion) {
+    use std::io::{Read, Write};
+
+    #[derive(serde::Deserialize)]
+    struct Page {
+        attacks: Vec<torn_client::models::user::Attack>,
+    }
+
+    let json = attacks_fixture(1000);
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(json.as_bytes()).unwrap();
+    let gzipped = encoder.finish().unwrap();
+
+    let mut group = c.benchmark_group("attacks_page_wire_decode");
+    group.throughput(criterion::Throughput::Bytes(json.len() as u64));
+    group.bench_function("plain", |b| {
+        b.iter(|| {
+            let page: Page = serde_json::from_slice(black_box(json.as_bytes())).unwrap();
+            black_box(page.attacks.len())
+        })
+    });
+    group.throughput(criterion::Throughput::Bytes(gzipped.len() as u64));
+    group.bench_function("gzipped", |b| {
+        b.iter(|| {
+            let mut body = Vec::with_capacity(json.len());
+            flate2::read::GzDecoder::new(black_box(gzipped.as_slice()))
+                .read_to_end(&mut body)
+                .unwrap();
+            let page: Page = serde_json::from_slice(&body).unwrap();
+            black_box(page.attacks.len())
+        })
+    });
+    group.finish();
+}
+
+/// Parsing a `_metadata.links.next` cursor back into host + query parts.
+fn bench_pagination_url_parsing(c: &mut Criterion) {
+    let next =
+        "https://api.torn.com/v2/faction/attacks?limit=100&sort=DESC&to=1699999999&from=1699000000";
+    c.bench_function("pagination_url_parsing", |b| {
+        b.iter(|| {
+            let url = url::Url::parse(black_box(next)).unwrap();
+            let pairs: Vec<(String, String)> = url
+                .query_pairs()
+                .map(|(k, v)| (k.into_owned(), v.into_owned()))
+                .collect();
+            black_box(pairs)
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_url_building,
+    bench_deserialization,
+    bench_limiter_contention,
+    bench_limiter_saturated,
+    bench_compressed_page_decode,
+    bench_pagination_url_parsing
+);
+criterion_main!(benches);
@@ -0,0 +1,204 @@
+//! Retry backoff policies.
+//!
+//! A [`Backoff`] decides how long to wait before retrying a failed request —
+//! or whether to give up. The client retries nothing by default; install a
+//! policy with [`crate::TornClientConfig::retry_backoff`] and transient
+//! failures (timeouts, error 5, temporary backend errors) are retried
+//! automatically.
+
+use std::time::Duration;
+
+use crate::TornError;
+
+/// Decides the delay before retry number `attempt` (1-based) of a failed
+/// request, or `None` to stop retrying and surface the error.
+pub trait Backoff: Send + Sync + std::fmt::Debug {
+    fn next_delay(&self, attempt: u32, error: &TornError) -> Option<Duration>;
+}
+
+/// Exponential backoff with full jitter: attempt `n` waits a uniformly random
+/// duration up to `base * 2^(n-1)`, capped at `max_delay`, for at most
+/// `max_attempts` retries. The jitter spreads out clients that failed at the
+/// same moment (e.g. everyone hitting error 5 as a minute window rolls over).
+#[derive(Debug, Clone)]
+pub struct ExponentialBackoff {
+    base: Duration,
+    max_delay: Duration,
+    max_attempts: u32,
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self {
+            base: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            max_attempts: 4,
+        }
+    }
+}
+
+impl ExponentialBackoff {
+    /// Policy with the given first-retry delay, delay cap and retry count.
+    pub fn new(base: Duration, max_delay: Duration, max_attempts: u32) -> Self {
+        Self {
+            base,
+            max_delay,
+            max_attempts,
+        }
+    }
+}
+
+impl Backoff for ExponentialBackoff {
+    fn next_delay(&self, attempt: u32, _error: &TornError) -> Option<Duration> {
+        if attempt > self.max_attempts {
+            return None;
+        }
+        let ceiling = self
+            .base
+            .saturating_mul(1u32 << attempt.saturating_sub(1).min(16))
+            .min(self.max_delay);
+        Some(jitter(ceiling))
+    }
+}
+
+/// Fixed-step linear backoff: attempt `n` waits `step * n`, without jitter.
+/// Predictable latency for interactive bots that would rather fail fast.
+#[derive(Debug, Clone)]
+pub struct LinearBackoff {
+    step: Duration,
+    max_attempts: u32,
+}
+
+impl LinearBackoff {
+    /// Policy waiting `step`, `2 * step`, ... for at most `max_attempts`
+    /// retries.
+    pub fn new(step: Duration, max_attempts: u32) -> Self {
+        Self { step, max_attempts }
+    }
+}
+
+impl Backoff for LinearBackoff {
+    fn next_delay(&self, attempt: u32, _error: &TornError) -> Option<Duration> {
+        if attempt > self.max_attempts {
+            return None;
+        }
+        Some(self.step.saturating_mul(attempt))
+    }
+}
+
+/// A uniformly random duration in `[0, ceiling]`, seeded from the clock so no
+/// extra dependency is needed; backoff jitter does not need to be
+/// cryptographic, just decorrelated across processes.
+fn jitter(ceiling: Duration) -> Duration {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0) as u64;
+    let ceiling_nanos = ceiling.as_nanos() as u64;
+    if ceiling_nanos == 0 {
+        return Duration::ZERO;
+    }
+    Duration::from_nanos(nanos.wrapping_mul(0x9E37_79B9_7F4A_7C15) % ceiling_nanos)
+}
+
+/// How far back requests and retries count toward the retry budget.
+const RETRY_BUDGET_WINDOW: Duration = Duration::from_secs(60);
+
+/// Caps retries at a fraction of recent request volume, so a brownout plus a
+/// retry policy cannot multiply traffic exactly when the API is struggling.
+/// Shared by all clones of a client; when the budget is spent, retries are
+/// skipped and the error surfaces immediately.
+#[derive(Debug)]
+pub(crate) struct RetryBudget {
+    ratio: f64,
+    // (instant, is_retry) per send inside the window, oldest first.
+    events: std::sync::Mutex<std::collections::VecDeque<(std::time::Instant, bool)>>,
+}
+
+impl RetryBudget {
+    pub(crate) fn new(ratio: f64) -> Self {
+        Self {
+            ratio,
+            events: std::sync::Mutex::new(std::collections::VecDeque::new()),
+        }
+    }
+
+    /// Records one initial (non-retry) request.
+    pub(crate) fn record_request(&self) {
+        let mut events = self.events.lock().expect("retry budget poisoned");
+        Self::prune(&mut events);
+        events.push_back((std::time::Instant::now(), false));
+    }
+
+    /// Withdraws one retry from the budget, or returns `false` when retries
+    /// already make up more than `ratio` of recent traffic.
+    pub(crate) fn try_withdraw(&self) -> bool {
+        let mut events = self.events.lock().expect("retry budget poisoned");
+        Self::prune(&mut events);
+        let requests = events.iter().filter(|(_, is_retry)| !is_retry).count();
+        let retries = events.iter().filter(|(_, is_retry)| *is_retry).count();
+        if (retries + 1) as f64 > self.ratio * requests as f64 {
+            return false;
+        }
+        events.push_back((std::time::Instant::now(), true));
+        true
+    }
+
+    fn prune(events: &mut std::collections::VecDeque<(std::time::Instant, bool)>) {
+        let now = std::time::Instant::now();
+        while let Some((at, _)) = events.front() {
+            if now.duration_since(*at) < RETRY_BUDGET_WINDOW {
+                break;
+            }
+            events.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exponential_caps_and_gives_up() {
+        let policy = ExponentialBackoff::new(Duration::from_secs(1), Duration::from_secs(4), 3);
+        let error = TornError::RateLimited { wait: None };
+        for attempt in 1..=3 {
+            let delay = policy.next_delay(attempt, &error).unwrap();
+            assert!(delay <= Duration::from_secs(4));
+        }
+        assert!(policy.next_delay(4, &error).is_none());
+    }
+
+    #[test]
+    fn retry_budget_caps_retries_to_a_share_of_traffic() {
+        let budget = RetryBudget::new(0.2);
+        for _ in 0..10 {
+            budget.record_request();
+        }
+        assert!(budget.try_withdraw());
+        assert!(budget.try_withdraw());
+        assert!(!budget.try_withdraw());
+    }
+
+    #[test]
+    fn empty_budget_allows_no_retries() {
+        let budget = RetryBudget::new(0.2);
+        assert!(!budget.try_withdraw());
+    }
+
+    #[test]
+    fn linear_grows_by_fixed_steps() {
+        let policy = LinearBackoff::new(Duration::from_millis(100), 2);
+        let error = TornError::RateLimited { wait: None };
+        assert_eq!(
+            policy.next_delay(1, &error),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(
+            policy.next_delay(2, &error),
+            Some(Duration::from_millis(200))
+        );
+        assert_eq!(policy.next_delay(3, &error), None);
+    }
+}
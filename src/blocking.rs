@@ -0,0 +1,381 @@
+//! Blocking client (the `blocking` feature).
+//!
+//! [`TornClient`] here wraps the async client and an internal
+//! current-thread tokio runtime, so the rate limiter, key pool, retry and
+//! health logic are exactly the ones the async client uses — each call just
+//! blocks until its future resolves.
+//!
+//! ```no_run
+//! # fn run() -> Result<(), torn_client::TornError> {
+//! use torn_client::blocking::TornClient;
+//! use torn_client::TornClientConfig;
+//!
+//! let client = TornClient::new(TornClientConfig::new("API_KEY"));
+//! let profile = client.user().profile()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Do not use this client from inside an async runtime — blocking on a
+//! nested runtime panics. Streaming helpers (`attacks_between`,
+//! `into_stream`) stay async-only; from blocking code, page through
+//! [`PaginatedResponse`] with [`TornClient::block_on`].
+
+use std::future::Future;
+use std::sync::Arc;
+
+use crate::client::TornClientConfig;
+use crate::ids::{FactionId, ItemId, UserId};
+use crate::models::faction::{
+    FactionBasic, FactionHof, FactionMember, FactionNews, FactionPosition,
+};
+use crate::models::key::KeyInfo;
+use crate::models::market::ItemMarket;
+use crate::models::racing::Race;
+use crate::models::torn::{
+    Honor, Item, LogCategory, LogType, Medal, Racket, RacketTerritory, Territory, TornCrime,
+};
+use crate::models::user::{Attack, Revive, UserEvent, UserProfile};
+use crate::pagination::PaginatedResponse;
+use crate::Result;
+
+/// Blocking counterpart of [`crate::TornClient`].
+#[derive(Debug, Clone)]
+pub struct TornClient {
+    inner: crate::TornClient,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TornClient {
+    /// Constructs a blocking client from the given configuration.
+    pub fn new(config: TornClientConfig) -> Self {
+        Self::from_async(crate::TornClient::new(config))
+    }
+
+    /// Wraps an existing async client, sharing its key pool, limiter and
+    /// connection pool.
+    pub fn from_async(inner: crate::TornClient) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to build blocking runtime");
+        Self {
+            inner,
+            runtime: Arc::new(runtime),
+        }
+    }
+
+    /// The async client this handle wraps.
+    pub fn as_async(&self) -> &crate::TornClient {
+        &self.inner
+    }
+
+    /// Drives any future from the async API to completion, e.g. to follow
+    /// [`PaginatedResponse::next_page`] from blocking code.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Handle for `/user/...` endpoints.
+    pub fn user(&self) -> UserEndpoint {
+        UserEndpoint {
+            client: self.clone(),
+        }
+    }
+
+    /// Handle for `/faction/...` endpoints.
+    pub fn faction(&self) -> FactionEndpoint {
+        FactionEndpoint {
+            client: self.clone(),
+        }
+    }
+
+    /// Handle for `/market/...` endpoints.
+    pub fn market(&self) -> MarketEndpoint {
+        MarketEndpoint {
+            client: self.clone(),
+        }
+    }
+
+    /// Handle for `/torn/...` endpoints.
+    pub fn torn(&self) -> TornEndpoint {
+        TornEndpoint {
+            client: self.clone(),
+        }
+    }
+
+    /// Handle for `/racing/...` endpoints.
+    pub fn racing(&self) -> RacingEndpoint {
+        RacingEndpoint {
+            client: self.clone(),
+        }
+    }
+
+    /// Handle for `/key/...` endpoints.
+    pub fn key(&self) -> KeyEndpoint {
+        KeyEndpoint {
+            client: self.clone(),
+        }
+    }
+}
+
+/// Blocking counterpart of [`crate::endpoints::UserEndpoint`].
+#[derive(Debug, Clone)]
+pub struct UserEndpoint {
+    client: TornClient,
+}
+
+impl UserEndpoint {
+    /// Scopes subsequent calls to another player's ID.
+    pub fn id(&self, id: impl Into<UserId>) -> UserIdContext {
+        UserIdContext {
+            client: self.client.clone(),
+            id: id.into(),
+        }
+    }
+
+    /// `GET /user/profile`
+    pub fn profile(&self) -> Result<UserProfile> {
+        self.client.block_on(self.client.inner.user().profile())
+    }
+
+    /// `GET /user/attacks`
+    pub fn attacks(&self) -> Result<PaginatedResponse<Attack>> {
+        self.client.block_on(self.client.inner.user().attacks())
+    }
+
+    /// `GET /user/revives`
+    pub fn revives(&self) -> Result<PaginatedResponse<Revive>> {
+        self.client.block_on(self.client.inner.user().revives())
+    }
+
+    /// `GET /user/events`
+    pub fn events(&self) -> Result<PaginatedResponse<UserEvent>> {
+        self.client.block_on(self.client.inner.user().events())
+    }
+}
+
+/// Blocking counterpart of [`crate::endpoints::UserIdContext`].
+#[derive(Debug, Clone)]
+pub struct UserIdContext {
+    client: TornClient,
+    id: UserId,
+}
+
+impl UserIdContext {
+    /// `GET /user/{id}/profile`
+    pub fn profile(&self) -> Result<UserProfile> {
+        self.client
+            .block_on(self.client.inner.user().id(self.id.clone()).profile())
+    }
+}
+
+/// Blocking counterpart of [`crate::endpoints::FactionEndpoint`].
+#[derive(Debug, Clone)]
+pub struct FactionEndpoint {
+    client: TornClient,
+}
+
+impl FactionEndpoint {
+    /// Scopes subsequent calls to another faction's ID.
+    pub fn id(&self, id: impl Into<FactionId>) -> FactionIdContext {
+        FactionIdContext {
+            client: self.client.clone(),
+            id: id.into(),
+        }
+    }
+
+    /// `GET /faction/basic`
+    pub fn basic(&self) -> Result<FactionBasic> {
+        self.client.block_on(self.client.inner.faction().basic())
+    }
+
+    /// `GET /faction/members`
+    pub fn members(&self) -> Result<Vec<FactionMember>> {
+        self.client.block_on(self.client.inner.faction().members())
+    }
+
+    /// `GET /faction/hof`
+    pub fn hof(&self) -> Result<FactionHof> {
+        self.client.block_on(self.client.inner.faction().hof())
+    }
+
+    /// `GET /faction/positions`
+    pub fn positions(&self) -> Result<Vec<FactionPosition>> {
+        self.client
+            .block_on(self.client.inner.faction().positions())
+    }
+
+    /// `GET /faction/rackets` joined with `GET /torn/territory`.
+    pub fn rackets_with_territories(&self) -> Result<Vec<RacketTerritory>> {
+        self.client
+            .block_on(self.client.inner.faction().rackets_with_territories())
+    }
+
+    /// `GET /faction/attacks`
+    pub fn attacks(&self) -> Result<PaginatedResponse<Attack>> {
+        self.client.block_on(self.client.inner.faction().attacks())
+    }
+
+    /// `GET /faction/revives`
+    pub fn revives(&self) -> Result<PaginatedResponse<Revive>> {
+        self.client.block_on(self.client.inner.faction().revives())
+    }
+
+    /// `GET /faction/news`
+    pub fn news(&self) -> Result<PaginatedResponse<FactionNews>> {
+        self.client.block_on(self.client.inner.faction().news())
+    }
+}
+
+/// Blocking counterpart of [`crate::endpoints::FactionIdContext`].
+#[derive(Debug, Clone)]
+pub struct FactionIdContext {
+    client: TornClient,
+    id: FactionId,
+}
+
+impl FactionIdContext {
+    /// `GET /faction/{id}/basic`
+    pub fn basic(&self) -> Result<FactionBasic> {
+        self.client
+            .block_on(self.client.inner.faction().id(self.id.clone()).basic())
+    }
+
+    /// `GET /faction/{id}/members`
+    pub fn members(&self) -> Result<Vec<FactionMember>> {
+        self.client
+            .block_on(self.client.inner.faction().id(self.id.clone()).members())
+    }
+}
+
+/// Blocking counterpart of [`crate::endpoints::MarketEndpoint`].
+#[derive(Debug, Clone)]
+pub struct MarketEndpoint {
+    client: TornClient,
+}
+
+impl MarketEndpoint {
+    /// Scopes subsequent calls to an item ID.
+    pub fn item(&self, item_id: impl Into<ItemId>) -> MarketItemContext {
+        MarketItemContext {
+            client: self.client.clone(),
+            item_id: item_id.into(),
+        }
+    }
+}
+
+/// Blocking counterpart of [`crate::endpoints::MarketItemContext`].
+#[derive(Debug, Clone)]
+pub struct MarketItemContext {
+    client: TornClient,
+    item_id: ItemId,
+}
+
+impl MarketItemContext {
+    /// `GET /market/{id}/itemmarket`
+    pub fn itemmarket(&self) -> Result<ItemMarket> {
+        self.client.block_on(
+            self.client
+                .inner
+                .market()
+                .item(self.item_id.clone())
+                .itemmarket(),
+        )
+    }
+}
+
+/// Blocking counterpart of [`crate::endpoints::TornEndpoint`].
+#[derive(Debug, Clone)]
+pub struct TornEndpoint {
+    client: TornClient,
+}
+
+impl TornEndpoint {
+    /// `GET /torn/timestamp`
+    pub fn timestamp(&self) -> Result<i64> {
+        self.client.block_on(self.client.inner.torn().timestamp())
+    }
+
+    /// `GET /torn/logcategories`
+    pub fn log_categories(&self) -> Result<Vec<LogCategory>> {
+        self.client
+            .block_on(self.client.inner.torn().log_categories())
+    }
+
+    /// `GET /torn/logtypes`
+    pub fn log_types(&self) -> Result<Vec<LogType>> {
+        self.client.block_on(self.client.inner.torn().log_types())
+    }
+
+    /// `GET /torn/items`
+    pub fn items(&self) -> Result<Vec<Item>> {
+        self.client.block_on(self.client.inner.torn().items())
+    }
+
+    /// `GET /torn/rackets`
+    pub fn rackets(&self) -> Result<Vec<Racket>> {
+        self.client.block_on(self.client.inner.torn().rackets())
+    }
+
+    /// `GET /torn/territory`
+    pub fn territories(&self) -> Result<Vec<Territory>> {
+        self.client.block_on(self.client.inner.torn().territories())
+    }
+
+    /// `GET /torn/crimes`
+    pub fn crimes(&self) -> Result<Vec<TornCrime>> {
+        self.client.block_on(self.client.inner.torn().crimes())
+    }
+
+    /// `GET /torn/honors`
+    pub fn honors(&self) -> Result<Vec<Honor>> {
+        self.client.block_on(self.client.inner.torn().honors())
+    }
+
+    /// `GET /torn/medals`
+    pub fn medals(&self) -> Result<Vec<Medal>> {
+        self.client.block_on(self.client.inner.torn().medals())
+    }
+}
+
+/// Blocking counterpart of [`crate::endpoints::RacingEndpoint`].
+#[derive(Debug, Clone)]
+pub struct RacingEndpoint {
+    client: TornClient,
+}
+
+impl RacingEndpoint {
+    /// `GET /racing/races`
+    pub fn races(&self) -> Result<PaginatedResponse<Race>> {
+        self.client.block_on(self.client.inner.racing().races())
+    }
+}
+
+/// Blocking counterpart of [`crate::endpoints::KeyEndpoint`].
+#[derive(Debug, Clone)]
+pub struct KeyEndpoint {
+    client: TornClient,
+}
+
+impl KeyEndpoint {
+    /// `GET /key/info`
+    pub fn info(&self) -> Result<KeyInfo> {
+        self.client.block_on(self.client.inner.key().info())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_share_the_async_client_state() {
+        let blocking = TornClient::new(TornClientConfig::new("k"));
+        let user = blocking.user();
+        assert!(std::sync::Arc::ptr_eq(
+            &blocking.runtime,
+            &user.client.runtime
+        ));
+    }
+}
@@ -0,0 +1,86 @@
+//! Request budgets for bulk operations.
+//!
+//! A [`BudgetGuard`] caps how many requests one logical operation may issue,
+//! so a mistaken unbounded fetch cannot burn a whole key-pool minute. Bulk
+//! helpers accept a guard and stop cleanly with a resumable cursor instead of
+//! exceeding it.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// A cap on requests for one bulk invocation. Cheap, thread-safe, and shared
+/// by reference between the helpers taking part in the same operation.
+#[derive(Debug)]
+pub struct BudgetGuard {
+    limit: u32,
+    used: AtomicU32,
+}
+
+impl BudgetGuard {
+    /// A budget allowing at most `limit` requests.
+    pub fn new(limit: u32) -> Self {
+        Self {
+            limit,
+            used: AtomicU32::new(0),
+        }
+    }
+
+    /// Tries to reserve one request from the budget. Returns `false` once the
+    /// budget is exhausted; the caller must then stop issuing requests.
+    pub fn try_acquire(&self) -> bool {
+        self.used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                (used < self.limit).then_some(used + 1)
+            })
+            .is_ok()
+    }
+
+    /// Requests reserved so far.
+    pub fn used(&self) -> u32 {
+        self.used.load(Ordering::Relaxed).min(self.limit)
+    }
+
+    /// Requests still available.
+    pub fn remaining(&self) -> u32 {
+        self.limit - self.used()
+    }
+}
+
+/// Result of a budgeted bulk fetch: what was collected, plus where to resume
+/// if the budget ran out before the data did.
+#[derive(Debug)]
+pub struct BudgetedFetch<T> {
+    /// Items collected within the budget.
+    pub items: Vec<T>,
+    /// Cursor of the first unfetched page; `None` when the data was exhausted.
+    pub resume_url: Option<String>,
+}
+
+impl<T> BudgetedFetch<T> {
+    /// Whether the fetch stopped early because the budget ran out.
+    pub fn is_partial(&self) -> bool {
+        self.resume_url.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget_refuses_after_limit() {
+        let budget = BudgetGuard::new(2);
+        assert!(budget.try_acquire());
+        assert!(budget.try_acquire());
+        assert!(!budget.try_acquire());
+        assert!(!budget.try_acquire());
+        assert_eq!(budget.used(), 2);
+        assert_eq!(budget.remaining(), 0);
+    }
+
+    #[test]
+    fn zero_budget_refuses_immediately() {
+        let budget = BudgetGuard::new(0);
+        assert!(!budget.try_acquire());
+        assert_eq!(budget.remaining(), 0);
+    }
+}
@@ -0,0 +1,200 @@
+//! Cached lookups for static reference data.
+
+use std::collections::HashMap;
+
+use crate::models::torn::{Item, LogCategory, LogType};
+
+/// Cached map of log types and categories from `/torn/logtypes` and
+/// `/torn/logcategories`.
+///
+/// Log entries reference types and categories by numeric ID; rendering and
+/// filter-building need the names (and the reverse). Fetch this once via
+/// [`crate::TornClient::log_catalog`] and query it locally after that.
+#[derive(Debug, Clone)]
+pub struct LogCatalog {
+    types: HashMap<u32, String>,
+    categories: HashMap<u32, String>,
+}
+
+impl LogCatalog {
+    pub(crate) fn new(types: Vec<LogType>, categories: Vec<LogCategory>) -> Self {
+        Self {
+            types: types.into_iter().map(|t| (t.id, t.title)).collect(),
+            categories: categories.into_iter().map(|c| (c.id, c.title)).collect(),
+        }
+    }
+
+    /// Name of a log type, if the ID is known.
+    pub fn type_name(&self, id: u32) -> Option<&str> {
+        self.types.get(&id).map(String::as_str)
+    }
+
+    /// Name of a log category, if the ID is known.
+    pub fn category_name(&self, id: u32) -> Option<&str> {
+        self.categories.get(&id).map(String::as_str)
+    }
+
+    /// Reverse lookup of a log type by name, case-insensitively.
+    pub fn type_id(&self, name: &str) -> Option<u32> {
+        self.types
+            .iter()
+            .find(|(_, title)| title.eq_ignore_ascii_case(name))
+            .map(|(id, _)| *id)
+    }
+
+    /// Reverse lookup of a log category by name, case-insensitively.
+    pub fn category_id(&self, name: &str) -> Option<u32> {
+        self.categories
+            .iter()
+            .find(|(_, title)| title.eq_ignore_ascii_case(name))
+            .map(|(id, _)| *id)
+    }
+
+    /// Number of known log types.
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    /// Whether the catalog holds no log types at all.
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+}
+
+/// Indexed view of the `/torn/items` catalog.
+///
+/// Answers "what's the ID for Xanax?" locally: lookup by ID, ranked name
+/// search, and filtering by item type. Fetch once via
+/// [`crate::TornClient::item_catalog`] and query it from there on.
+#[derive(Debug, Clone)]
+pub struct ItemCatalog {
+    items: Vec<Item>,
+    by_id: HashMap<u64, usize>,
+}
+
+impl ItemCatalog {
+    pub(crate) fn new(items: Vec<Item>) -> Self {
+        let by_id = items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| (item.id, index))
+            .collect();
+        Self { items, by_id }
+    }
+
+    /// Looks an item up by its ID.
+    pub fn get(&self, id: u64) -> Option<&Item> {
+        self.by_id.get(&id).map(|&index| &self.items[index])
+    }
+
+    /// The ID of the item whose name matches exactly (case-insensitively).
+    pub fn id_of(&self, name: &str) -> Option<u64> {
+        self.items
+            .iter()
+            .find(|item| item.name.eq_ignore_ascii_case(name))
+            .map(|item| item.id)
+    }
+
+    /// Ranked name search: exact matches first, then prefix matches, then
+    /// substring matches, all case-insensitive.
+    pub fn search(&self, query: &str) -> Vec<&Item> {
+        let query = query.to_ascii_lowercase();
+        let mut ranked: Vec<(u8, &Item)> = self
+            .items
+            .iter()
+            .filter_map(|item| {
+                let name = item.name.to_ascii_lowercase();
+                let rank = if name == query {
+                    0
+                } else if name.starts_with(&query) {
+                    1
+                } else if name.contains(&query) {
+                    2
+                } else {
+                    return None;
+                };
+                Some((rank, item))
+            })
+            .collect();
+        ranked.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.name.cmp(&b.1.name)));
+        ranked.into_iter().map(|(_, item)| item).collect()
+    }
+
+    /// All items of the given type (e.g. `"Drug"`), case-insensitive.
+    pub fn by_type(&self, item_type: &str) -> Vec<&Item> {
+        self.items
+            .iter()
+            .filter(|item| item.item_type.eq_ignore_ascii_case(item_type))
+            .collect()
+    }
+
+    /// Number of items in the catalog.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether the catalog is empty.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::torn::ItemValue;
+
+    fn item(id: u64, name: &str, item_type: &str) -> Item {
+        Item {
+            id,
+            name: name.into(),
+            description: String::new(),
+            item_type: item_type.into(),
+            circulation: 0,
+            value: ItemValue {
+                buy_price: None,
+                sell_price: None,
+                market_price: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn item_search_ranks_exact_before_substring() {
+        let catalog = ItemCatalog::new(vec![
+            item(206, "Xanax", "Drug"),
+            item(1, "Box of Xanax", "Supply Pack"),
+            item(2, "Vicodin", "Drug"),
+        ]);
+        assert_eq!(catalog.id_of("xanax"), Some(206));
+        let hits: Vec<_> = catalog.search("xanax").iter().map(|i| i.id).collect();
+        assert_eq!(hits, vec![206, 1]);
+        assert_eq!(catalog.by_type("drug").len(), 2);
+        assert_eq!(catalog.get(2).unwrap().name, "Vicodin");
+    }
+
+    #[test]
+    fn lookups_work_both_ways() {
+        let catalog = LogCatalog::new(
+            vec![
+                LogType {
+                    id: 225,
+                    title: "Attack and win".into(),
+                },
+                LogType {
+                    id: 226,
+                    title: "Attack and lose".into(),
+                },
+            ],
+            vec![LogCategory {
+                id: 16,
+                title: "Attacking".into(),
+            }],
+        );
+        assert_eq!(catalog.type_name(225), Some("Attack and win"));
+        assert_eq!(catalog.type_id("attack and LOSE"), Some(226));
+        assert_eq!(catalog.category_id("Attacking"), Some(16));
+        assert_eq!(catalog.type_name(9999), None);
+        assert_eq!(catalog.len(), 2);
+    }
+}
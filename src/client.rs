@@ -0,0 +1,2912 @@
+//! The [`TornClient`] and its configuration.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::de::DeserializeOwned;
+use tokio::sync::Notify;
+
+use crate::endpoints::{
+    FactionEndpoint, KeyEndpoint, MarketEndpoint, RacingEndpoint, TornEndpoint, UserEndpoint,
+};
+use crate::error::ApiErrorEnvelope;
+use crate::keys::{ApiKeyBalancing, ApiKeyPool, SecretKeys};
+use crate::models::key::AccessLevel;
+use crate::rate_limit::{
+    AcquireContext, BudgetReservation, IpRateLimiter, Priority, RateLimitMode, RateLimiter,
+};
+use crate::{Result, TornError};
+
+/// Default base URL of the Torn v2 API.
+pub const DEFAULT_BASE_URL: &str = "https://api.torn.com/v2";
+
+/// Configuration used to construct a [`TornClient`].
+#[derive(Debug, Clone)]
+pub struct TornClientConfig {
+    pub(crate) keys: SecretKeys,
+    pub(crate) base_url: String,
+    pub(crate) rate_limit_mode: RateLimitMode,
+    pub(crate) slow_request_threshold: Duration,
+    pub(crate) pause_mode: PauseMode,
+    pub(crate) ip_limiter: Option<Arc<IpRateLimiter>>,
+    pub(crate) comment: Option<String>,
+    pub(crate) preflight_access_check: bool,
+    pub(crate) default_params: Vec<(String, String)>,
+    pub(crate) endpoint_default_params: HashMap<String, Vec<(String, String)>>,
+    pub(crate) ip_block_cooloff: Duration,
+    pub(crate) retry_backoff: Option<Arc<dyn crate::backoff::Backoff>>,
+    pub(crate) rate_limiter: Option<Arc<dyn crate::rate_limit::RateLimit>>,
+    pub(crate) log_redaction: LogRedaction,
+    pub(crate) retry_budget_ratio: f64,
+    pub(crate) http_client: Option<reqwest::Client>,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) connect_timeout: Option<Duration>,
+    pub(crate) proxy: Option<String>,
+    pub(crate) proxy_auth: Option<(String, String)>,
+    pub(crate) user_agent: Option<String>,
+    pub(crate) compression: bool,
+    pub(crate) pool_idle_timeout: Option<Duration>,
+    pub(crate) pool_max_idle_per_host: Option<usize>,
+    pub(crate) tcp_keepalive: Option<Duration>,
+    pub(crate) rate_limit_state: Option<crate::rate_limit::RateLimiterSnapshot>,
+    pub(crate) on_throttle: Option<ThrottleHook>,
+    pub(crate) max_rate_limit_wait: Option<Duration>,
+    pub(crate) rate_limit_margin: f64,
+    pub(crate) cancellation_token: Option<tokio_util::sync::CancellationToken>,
+    pub(crate) on_key_invalidated: Option<KeyInvalidatedHook>,
+    pub(crate) key_balancing: ApiKeyBalancing,
+    pub(crate) key_labels: KeyLabels,
+    pub(crate) middleware: MiddlewareChain,
+    pub(crate) request_id_header: Option<String>,
+}
+
+/// Label map keyed by raw API key; `Debug` shows the keys redacted, so the
+/// config as a whole stays safe to log.
+#[derive(Clone, Default)]
+pub(crate) struct KeyLabels(HashMap<String, String>);
+
+impl std::fmt::Debug for KeyLabels {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.0.iter().map(|(key, label)| (redact_key(key), label)))
+            .finish()
+    }
+}
+
+impl std::ops::Deref for KeyLabels {
+    type Target = HashMap<String, String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for KeyLabels {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Details of one rate limit wait, passed to the hook registered via
+/// [`TornClientConfig::on_throttle`].
+#[derive(Debug, Clone)]
+pub struct ThrottleEvent {
+    /// The pool key that hit its budget, redacted the same way the usage
+    /// tracker stores it.
+    pub key: String,
+    /// The URL the throttled request was headed for.
+    pub url: String,
+    /// How long the limiter is about to wait before sending.
+    pub wait: Duration,
+}
+
+/// Boxed `on_throttle` callback; a wrapper so the config stays `Debug`.
+#[derive(Clone)]
+pub(crate) struct ThrottleHook(Arc<dyn Fn(ThrottleEvent) + Send + Sync>);
+
+impl std::fmt::Debug for ThrottleHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ThrottleHook")
+    }
+}
+
+/// Registered middleware in registration order; a wrapper so the config
+/// stays `Debug`.
+#[derive(Clone, Default)]
+pub(crate) struct MiddlewareChain(pub(crate) Vec<Arc<dyn crate::middleware::Middleware>>);
+
+impl std::fmt::Debug for MiddlewareChain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("MiddlewareChain")
+            .field(&self.0.len())
+            .finish()
+    }
+}
+
+/// Details of one key quarantine, passed to the hook registered via
+/// [`TornClientConfig::on_key_invalidated`].
+#[derive(Debug, Clone)]
+pub struct KeyInvalidatedEvent {
+    /// The quarantined key, redacted the same way logs show it.
+    pub key: String,
+    /// Operator label for the key, when one was attached via
+    /// [`TornClientConfig::api_key_labeled`].
+    pub label: Option<String>,
+    /// The Torn error code that condemned it (2, 10 or 13).
+    pub code: u16,
+    /// The server's error message.
+    pub message: String,
+}
+
+/// Boxed `on_key_invalidated` callback; a wrapper so the config stays
+/// `Debug`.
+#[derive(Clone)]
+pub(crate) struct KeyInvalidatedHook(Arc<dyn Fn(KeyInvalidatedEvent) + Send + Sync>);
+
+impl std::fmt::Debug for KeyInvalidatedHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("KeyInvalidatedHook")
+    }
+}
+
+/// The `User-Agent` sent unless overridden; Torn staff prefer identifiable
+/// tooling.
+pub const DEFAULT_USER_AGENT: &str = concat!("torn-client/", env!("CARGO_PKG_VERSION"));
+
+/// Default cap on retries as a fraction of recent request volume.
+pub const DEFAULT_RETRY_BUDGET_RATIO: f64 = 0.2;
+
+/// What the client scrubs from URLs before they reach tracing output, so
+/// verbose logs can be shared without leaking identifying data. The default
+/// policy redacts nothing beyond API keys (which are never logged in full).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LogRedaction {
+    redact_params: bool,
+    redact_comment: bool,
+    hash_ids: bool,
+}
+
+impl LogRedaction {
+    /// Policy that scrubs nothing beyond API keys.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces all query parameters with `<redacted>` in logged URLs.
+    pub fn redact_params(mut self) -> Self {
+        self.redact_params = true;
+        self
+    }
+
+    /// Redacts only the `comment` query parameter, which often identifies
+    /// the tool or player running it.
+    pub fn redact_comment(mut self) -> Self {
+        self.redact_comment = true;
+        self
+    }
+
+    /// Replaces numeric path segments (player, faction and item IDs) with a
+    /// stable hash, so logs still correlate per entity without naming it.
+    pub fn hash_ids(mut self) -> Self {
+        self.hash_ids = true;
+        self
+    }
+
+    /// Renders `url` plus `query` for log output under this policy.
+    pub(crate) fn render(&self, url: &str, query: &[(String, String)]) -> String {
+        let mut display = if self.hash_ids {
+            url.split('/')
+                .map(|segment| {
+                    if !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) {
+                        format!("id#{:08x}", hash_segment(segment))
+                    } else {
+                        segment.to_owned()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("/")
+        } else {
+            url.to_owned()
+        };
+        if query.is_empty() {
+            return display;
+        }
+        if self.redact_params {
+            display.push_str("?<redacted>");
+            return display;
+        }
+        for (index, (name, value)) in query.iter().enumerate() {
+            display.push(if index == 0 { '?' } else { '&' });
+            display.push_str(name);
+            display.push('=');
+            if self.redact_comment && name == "comment" {
+                display.push_str("<redacted>");
+            } else {
+                display.push_str(value);
+            }
+        }
+        display
+    }
+}
+
+/// Stable (per-process) hash for ID path segments in redacted logs.
+fn hash_segment(segment: &str) -> u32 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    segment.hash(&mut hasher);
+    hasher.finish() as u32
+}
+
+/// Default cool-off after the API reports an IP block (error code 8).
+pub const DEFAULT_IP_BLOCK_COOLOFF: Duration = Duration::from_secs(60);
+
+/// Default threshold above which a request is logged and counted as slow.
+pub const DEFAULT_SLOW_REQUEST_THRESHOLD: Duration = Duration::from_secs(2);
+
+/// Outcome of [`TornClient::shutdown`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShutdownReport {
+    /// Whether every in-flight request finished within the timeout.
+    pub drained: bool,
+    /// Requests still in flight when the timeout expired.
+    pub aborted_in_flight: u64,
+}
+
+/// One-off tweaks for a single request, accepted by the `_with_options`
+/// endpoint variants and [`TornClient::get_with_options`], so a one-time
+/// header or deadline does not require new builder state on the client.
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    pub(crate) params: Vec<(String, String)>,
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) bypass_cache: bool,
+    pub(crate) rate_limit_mode: Option<RateLimitMode>,
+    pub(crate) priority: Priority,
+    pub(crate) reservation: Option<u64>,
+    pub(crate) cancellation_token: Option<tokio_util::sync::CancellationToken>,
+    pub(crate) meta_sink: Option<MetaSink>,
+    pub(crate) request_id: Option<String>,
+}
+
+/// Slot [`TornClient::get_enveloped`] hands down through the request path to
+/// pick up the final attempt's [`ResponseMeta`]; a wrapper so the options
+/// stay `Debug`.
+#[derive(Clone, Default)]
+pub(crate) struct MetaSink(Arc<std::sync::Mutex<Option<ResponseMeta>>>);
+
+impl std::fmt::Debug for MetaSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MetaSink")
+    }
+}
+
+/// Request-level metadata attached to a [`ResponseEnvelope`]: where the call
+/// went, how it was answered and how long it took. With retries configured
+/// it describes the final attempt.
+#[derive(Debug, Clone)]
+pub struct ResponseMeta {
+    /// The URL the request was sent to.
+    pub url: String,
+    /// The HTTP status code. Torn reports API errors as a 200 with an error
+    /// envelope, so a 200 here does not by itself mean success.
+    pub status: u16,
+    /// Response headers, names lowercased.
+    pub headers: Vec<(String, String)>,
+    /// Time from sending the request to the last body byte.
+    pub elapsed: Duration,
+    /// The API key that served the request, redacted the same way the usage
+    /// tracker stores it.
+    pub key: String,
+    /// The call's correlation ID, when request IDs are enabled; see
+    /// [`TornClientConfig::request_id_header`].
+    pub request_id: Option<String>,
+}
+
+/// A decoded response together with its [`ResponseMeta`], returned by
+/// [`TornClient::get_enveloped`] — for debugging slow endpoints and
+/// attributing traffic per key without a middleware.
+#[derive(Debug, Clone)]
+pub struct ResponseEnvelope<T> {
+    /// The decoded response body.
+    pub data: T,
+    /// How the exchange went.
+    pub meta: ResponseMeta,
+}
+
+impl RequestOptions {
+    /// Options that change nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an extra query parameter (explicit parameters win over it on
+    /// name conflicts).
+    pub fn param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.params.push((name.into(), value.into()));
+        self
+    }
+
+    /// Adds a header to this request only.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Overrides the request timeout for this call, taking precedence over
+    /// [`TornClientConfig::timeout`]. Ignored on wasm, where reqwest has no
+    /// per-request deadline.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Appends a fresh `timestamp` parameter so any intermediary cache is
+    /// bypassed.
+    pub fn bypass_cache(mut self) -> Self {
+        self.bypass_cache = true;
+        self
+    }
+
+    /// Uses `id` as this call's correlation ID instead of a generated one,
+    /// so an inbound ID from an upstream service carries straight through;
+    /// see [`TornClientConfig::request_id_header`]. The ID travels in logs
+    /// and error context regardless; the header itself is only sent when
+    /// one is configured.
+    pub fn request_id(mut self, id: impl Into<String>) -> Self {
+        self.request_id = Some(id.into());
+        self
+    }
+
+    /// Overrides the rate limit mode for this call only, taking precedence
+    /// over both the configured mode and a
+    /// [`TornClient::with_rate_limit_mode`] scope — e.g. fail an interactive
+    /// command fast while background syncs on the same client keep
+    /// [`RateLimitMode::AutoDelay`].
+    pub fn rate_limit_mode(mut self, mode: RateLimitMode) -> Self {
+        self.rate_limit_mode = Some(mode);
+        self
+    }
+
+    /// Tags this call's urgency: when quota is scarce,
+    /// [`Priority::High`] waiters are granted slots before
+    /// [`Priority::Normal`] ones, which go before
+    /// [`Priority::Background`] — so an interactive command is not stuck
+    /// behind a pagination sweep.
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Draws this call from a [`BudgetReservation`]'s earmark, so it runs
+    /// at the full cap instead of the headroom left to unreserved traffic.
+    pub fn reservation(mut self, reservation: &BudgetReservation) -> Self {
+        self.reservation = Some(reservation.id());
+        self
+    }
+
+    /// Aborts this call with [`TornError::Cancelled`] the moment `token`
+    /// is cancelled, including mid-wait and mid-exchange. Takes precedence
+    /// over a client-wide [`TornClientConfig::cancellation_token`].
+    pub fn cancellation_token(mut self, token: tokio_util::sync::CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+}
+
+/// How the API key is attached to a request: v2 uses an `Authorization`
+/// header, the legacy v1 API a `key=` query parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KeyAuth {
+    Header,
+    QueryParam,
+}
+
+/// How requests behave while the client is paused via [`TornClient::pause`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PauseMode {
+    /// New requests wait until [`TornClient::resume`] is called. The default.
+    #[default]
+    Wait,
+    /// New requests fail immediately with [`crate::TornError::Paused`].
+    Error,
+}
+
+impl TornClientConfig {
+    /// Configuration with a single API key and default settings.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            keys: SecretKeys::from(vec![key.into()]),
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            rate_limit_mode: RateLimitMode::default(),
+            slow_request_threshold: DEFAULT_SLOW_REQUEST_THRESHOLD,
+            pause_mode: PauseMode::default(),
+            ip_limiter: None,
+            comment: None,
+            preflight_access_check: false,
+            default_params: Vec::new(),
+            endpoint_default_params: HashMap::new(),
+            ip_block_cooloff: DEFAULT_IP_BLOCK_COOLOFF,
+            retry_backoff: None,
+            rate_limiter: None,
+            log_redaction: LogRedaction::default(),
+            retry_budget_ratio: DEFAULT_RETRY_BUDGET_RATIO,
+            http_client: None,
+            timeout: None,
+            connect_timeout: None,
+            proxy: None,
+            proxy_auth: None,
+            user_agent: None,
+            compression: true,
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            tcp_keepalive: None,
+            rate_limit_state: None,
+            on_throttle: None,
+            max_rate_limit_wait: None,
+            rate_limit_margin: 0.0,
+            cancellation_token: None,
+            on_key_invalidated: None,
+            key_balancing: ApiKeyBalancing::default(),
+            key_labels: KeyLabels::default(),
+            middleware: MiddlewareChain::default(),
+            request_id_header: None,
+        }
+    }
+
+    /// Configuration with several API keys used in rotation.
+    pub fn with_keys<I, S>(keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            keys: SecretKeys::from(keys.into_iter().map(Into::into).collect::<Vec<_>>()),
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            rate_limit_mode: RateLimitMode::default(),
+            slow_request_threshold: DEFAULT_SLOW_REQUEST_THRESHOLD,
+            pause_mode: PauseMode::default(),
+            ip_limiter: None,
+            comment: None,
+            preflight_access_check: false,
+            default_params: Vec::new(),
+            endpoint_default_params: HashMap::new(),
+            ip_block_cooloff: DEFAULT_IP_BLOCK_COOLOFF,
+            retry_backoff: None,
+            rate_limiter: None,
+            log_redaction: LogRedaction::default(),
+            retry_budget_ratio: DEFAULT_RETRY_BUDGET_RATIO,
+            http_client: None,
+            timeout: None,
+            connect_timeout: None,
+            proxy: None,
+            proxy_auth: None,
+            user_agent: None,
+            compression: true,
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            tcp_keepalive: None,
+            rate_limit_state: None,
+            on_throttle: None,
+            max_rate_limit_wait: None,
+            rate_limit_margin: 0.0,
+            cancellation_token: None,
+            on_key_invalidated: None,
+            key_balancing: ApiKeyBalancing::default(),
+            key_labels: KeyLabels::default(),
+            middleware: MiddlewareChain::default(),
+            request_id_header: None,
+        }
+    }
+
+    /// Configuration read from `TORN_*` environment variables, so services
+    /// can be deployed without bespoke config glue.
+    ///
+    /// Recognized variables:
+    /// - `TORN_API_KEY` — a single key, or `TORN_API_KEYS` — a
+    ///   comma-separated pool (the plural form wins when both are set).
+    ///   One of the two is required.
+    /// - `TORN_BASE_URL` — overrides the API base URL.
+    /// - `TORN_RATE_LIMIT_MODE` — `auto-delay`, `error` or `off`.
+    /// - `TORN_COMMENT` — validated like [`TornClientConfig::comment`].
+    /// - `TORN_PREFLIGHT_ACCESS_CHECK` — `true`/`false` (also `1`/`0`,
+    ///   `yes`/`no`).
+    ///
+    /// Malformed values fail with [`TornError::InvalidParams`] naming the
+    /// offending variable rather than being silently ignored.
+    pub fn from_env() -> Result<Self> {
+        Self::from_env_with(|name| std::env::var(name).ok())
+    }
+
+    fn from_env_with(var: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let keys: Vec<String> = match (var("TORN_API_KEYS"), var("TORN_API_KEY")) {
+            (Some(pool), _) => pool
+                .split(',')
+                .map(str::trim)
+                .filter(|key| !key.is_empty())
+                .map(str::to_owned)
+                .collect(),
+            (None, Some(key)) => vec![key],
+            (None, None) => Vec::new(),
+        };
+        if keys.is_empty() {
+            return Err(TornError::InvalidParams(
+                "neither TORN_API_KEY nor TORN_API_KEYS is set".to_owned(),
+            ));
+        }
+        let mut config = Self::with_keys(keys);
+        if let Some(base_url) = var("TORN_BASE_URL") {
+            config = config.base_url(base_url);
+        }
+        if let Some(mode) = var("TORN_RATE_LIMIT_MODE") {
+            let mode = mode.parse().map_err(|_| {
+                TornError::InvalidParams(format!(
+                    "TORN_RATE_LIMIT_MODE: unknown mode {mode:?} \
+                     (expected auto-delay, error or off)"
+                ))
+            })?;
+            config = config.rate_limit_mode(mode);
+        }
+        if let Some(comment) = var("TORN_COMMENT") {
+            config = config.comment(comment)?;
+        }
+        if let Some(flag) = var("TORN_PREFLIGHT_ACCESS_CHECK") {
+            let enabled = parse_env_bool("TORN_PREFLIGHT_ACCESS_CHECK", &flag)?;
+            config = config.preflight_access_check(enabled);
+        }
+        Ok(config)
+    }
+
+    /// Overrides the API base URL (useful for mocking in tests).
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Sets how the local rate limiter reacts when a key is exhausted.
+    pub fn rate_limit_mode(mut self, mode: RateLimitMode) -> Self {
+        self.rate_limit_mode = mode;
+        self
+    }
+
+    /// Sets the duration above which a request is logged and counted as slow.
+    pub fn slow_request_threshold(mut self, threshold: Duration) -> Self {
+        self.slow_request_threshold = threshold;
+        self
+    }
+
+    /// Sets whether paused clients queue new requests or fail them fast.
+    pub fn pause_mode(mut self, mode: PauseMode) -> Self {
+        self.pause_mode = mode;
+        self
+    }
+
+    /// Registers this client against a process-wide IP rate limiter. Pass
+    /// the same [`Arc`] to every client in the process so Torn's per-IP cap
+    /// is respected across all of them.
+    pub fn ip_limiter(mut self, limiter: Arc<IpRateLimiter>) -> Self {
+        self.ip_limiter = Some(limiter);
+        self
+    }
+
+    /// Sets the `comment` query parameter attached to every request, which
+    /// shows up in the key owner's API log. Validated against Torn's rules
+    /// (at most [`MAX_COMMENT_LENGTH`] characters, alphanumerics plus
+    /// `space`, `_`, `-` and `.`) so a bad value fails here rather than being
+    /// silently truncated or rejected by the server.
+    pub fn comment(mut self, comment: impl Into<String>) -> Result<Self> {
+        let comment = comment.into();
+        validate_comment(&comment)?;
+        self.comment = Some(comment);
+        Ok(self)
+    }
+
+    /// Enables the pre-flight access guard: once key capabilities have been
+    /// fetched (call [`TornClient::key_capabilities`] at startup), requests
+    /// the key cannot serve fail locally with
+    /// [`TornError::InsufficientAccess`] instead of spending a rate-limited
+    /// call on a guaranteed error 16.
+    pub fn preflight_access_check(mut self, enabled: bool) -> Self {
+        self.preflight_access_check = enabled;
+        self
+    }
+
+    /// Sets how long the client halts *all* outgoing traffic after the API
+    /// reports an IP block (error code 8). Continuing to send during a block
+    /// only extends it, so the freeze applies across every key in the pool.
+    pub fn ip_block_cooloff(mut self, cooloff: Duration) -> Self {
+        self.ip_block_cooloff = cooloff;
+        self
+    }
+
+    /// Sets the total per-request timeout (connect through body). Requests
+    /// exceeding it fail with [`TornError::Timeout`] instead of stalling the
+    /// caller indefinitely. Ignored when a custom transport is supplied via
+    /// [`TornClientConfig::http_client`] — configure that client directly.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the connect-phase timeout; see [`TornClientConfig::timeout`].
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Restores rate limiter window state captured by
+    /// [`TornClient::rate_limit_snapshot`] before a restart, so the new
+    /// process resumes mid-window instead of firing a fresh burst.
+    pub fn rate_limit_state(mut self, snapshot: crate::rate_limit::RateLimiterSnapshot) -> Self {
+        self.rate_limit_state = Some(snapshot);
+        self
+    }
+
+    /// Like [`TornClientConfig::rate_limit_state`], reading the snapshot
+    /// from a JSON file written by [`TornClient::save_rate_limit_state`]. A
+    /// missing file is not an error — first boots have no state to restore.
+    pub fn rate_limit_state_file(self, path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(self),
+            Err(e) => {
+                return Err(TornError::InvalidParams(format!(
+                    "failed to read {}: {e}",
+                    path.display()
+                )))
+            }
+        };
+        let snapshot = serde_json::from_str(&contents)?;
+        Ok(self.rate_limit_state(snapshot))
+    }
+
+    /// How long an idle connection stays in the pool before being closed
+    /// (default: reqwest's 90 seconds). Long-running pollers firing less
+    /// often than the idle timeout re-handshake on every request; raise this
+    /// past the polling interval to keep the connection warm. Ignored with
+    /// an injected [`TornClientConfig::http_client`].
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Maximum idle connections kept per host (default: unlimited). The
+    /// client only talks to one host, so this caps the pool outright.
+    /// Ignored with an injected [`TornClientConfig::http_client`].
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Enables TCP keepalive probes at the given interval, so NAT and
+    /// firewall state does not silently expire under a warm pooled
+    /// connection. Ignored with an injected
+    /// [`TornClientConfig::http_client`].
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Toggles gzip/brotli `Accept-Encoding` on responses (default: on).
+    /// Only effective with the `compression` feature, which pulls in the
+    /// decoders; large paginated attack and market pages shrink roughly
+    /// tenfold on the wire.
+    pub fn compression(mut self, enabled: bool) -> Self {
+        self.compression = enabled;
+        self
+    }
+
+    /// Replaces the `User-Agent` header entirely (default:
+    /// [`DEFAULT_USER_AGENT`]). Ignored when a custom transport is supplied
+    /// via [`TornClientConfig::http_client`].
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Prefixes an application identifier onto the default `User-Agent`,
+    /// yielding e.g. `my-war-bot/2.1 torn-client/0.4.0`.
+    pub fn app_user_agent(self, app: impl Into<String>) -> Self {
+        let combined = format!("{} {DEFAULT_USER_AGENT}", app.into());
+        self.user_agent(combined)
+    }
+
+    /// Routes all traffic through an HTTP or SOCKS proxy, e.g.
+    /// `http://proxy.internal:3128` or `socks5://127.0.0.1:9050`. The URL is
+    /// validated here so a typo fails at build time rather than on the first
+    /// request. Ignored when a custom transport is supplied via
+    /// [`TornClientConfig::http_client`].
+    pub fn proxy(mut self, url: impl Into<String>) -> Result<Self> {
+        let url = url.into();
+        reqwest::Proxy::all(&url)
+            .map_err(|e| TornError::InvalidParams(format!("invalid proxy url {url:?}: {e}")))?;
+        self.proxy = Some(url);
+        Ok(self)
+    }
+
+    /// Basic-auth credentials for the proxy set via [`TornClientConfig::proxy`].
+    pub fn proxy_auth(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.proxy_auth = Some((username.into(), password.into()));
+        self
+    }
+
+    /// Supplies a pre-configured [`reqwest::Client`] as the transport —
+    /// custom proxies, TLS setup, connection pools or middlewares — while
+    /// the key pool, rate limiting and everything else stay managed here.
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Sets what gets scrubbed from URLs in tracing output; see
+    /// [`LogRedaction`].
+    pub fn log_redaction(mut self, policy: LogRedaction) -> Self {
+        self.log_redaction = policy;
+        self
+    }
+
+    /// Replaces the built-in sliding-window limiter with a custom
+    /// [`crate::rate_limit::RateLimit`] implementation (distributed,
+    /// weighted, cost-based, ...). The per-IP limiter configured via
+    /// [`TornClientConfig::ip_limiter`] still applies on top.
+    pub fn rate_limiter(mut self, limiter: Arc<dyn crate::rate_limit::RateLimit>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Registers a hook fired whenever [`RateLimitMode::AutoDelay`] decides
+    /// to wait before sending, carrying the computed wait, the (redacted)
+    /// key and the target URL — so key saturation shows up in metrics
+    /// before it shows up as latency. The hook runs on the request path;
+    /// keep it cheap.
+    pub fn on_throttle(mut self, hook: impl Fn(ThrottleEvent) + Send + Sync + 'static) -> Self {
+        self.on_throttle = Some(ThrottleHook(Arc::new(hook)));
+        self
+    }
+
+    /// Registers a hook fired when a pool key is quarantined after the
+    /// server rejected it as invalid (codes 2, 10 and 13), so operators
+    /// learn about dead credentials from a callback instead of a failure
+    /// rate. The hook runs on the request path; keep it cheap.
+    pub fn on_key_invalidated(
+        mut self,
+        hook: impl Fn(KeyInvalidatedEvent) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_key_invalidated = Some(KeyInvalidatedHook(Arc::new(hook)));
+        self
+    }
+
+    /// Appends a middleware to the chain run around every request — custom
+    /// headers, audit logging, request rewriting; see
+    /// [`crate::middleware::Middleware`]. Middleware run in registration
+    /// order.
+    pub fn middleware(mut self, middleware: impl crate::middleware::Middleware + 'static) -> Self {
+        self.middleware.0.push(Arc::new(middleware));
+        self
+    }
+
+    /// Enables correlation IDs: every call generates a unique request ID,
+    /// sent to the server in the named header (`x-request-id`, typically),
+    /// stamped on verbose logs and wrapped around any failure as
+    /// [`TornError::WithRequestId`] — so a multi-service deployment can
+    /// trace one failing Torn call end to end. Callers propagating an
+    /// inbound ID can override the generated one per request via
+    /// [`RequestOptions::request_id`].
+    pub fn request_id_header(mut self, name: impl Into<String>) -> Self {
+        self.request_id_header = Some(name.into());
+        self
+    }
+
+    /// Adds `key` to the pool tagged with an operator-facing label — an
+    /// owner's name, usually. The label (never the key itself) then shows
+    /// up in logs, rate limit info and key-invalidation events, so faction
+    /// tools aggregating donated keys can report per-member usage.
+    pub fn api_key_labeled(mut self, key: impl Into<String>, label: impl Into<String>) -> Self {
+        let key = key.into();
+        self.key_labels.insert(key.clone(), label.into());
+        self.keys.push(key);
+        self
+    }
+
+    /// Selects how requests are spread over the key pool; defaults to
+    /// [`ApiKeyBalancing::RoundRobin`].
+    pub fn key_balancing(mut self, balancing: ApiKeyBalancing) -> Self {
+        self.key_balancing = balancing;
+        self
+    }
+
+    /// Caps how long [`RateLimitMode::AutoDelay`] may park one request.
+    /// Waits beyond the cap fail with [`TornError::RateLimited`] carrying
+    /// the would-be wait, so latency-sensitive callers can degrade
+    /// gracefully instead of blocking for up to a full window.
+    pub fn max_rate_limit_wait(mut self, max: Duration) -> Self {
+        self.max_rate_limit_wait = Some(max);
+        self
+    }
+
+    /// Ties every request from this client to `token`: once it is
+    /// cancelled, rate limit waits, retry sleeps and in-flight exchanges
+    /// all abort with [`TornError::Cancelled`] — so a service shutdown is
+    /// not stuck behind a minute-long `AutoDelay`. Scope or override per
+    /// call via [`RequestOptions::cancellation_token`].
+    pub fn cancellation_token(mut self, token: tokio_util::sync::CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+
+    /// Holds back a safety margin below Torn's per-key cap: `0.05` stops at
+    /// 95 of 100 requests, so clock skew between client and server cannot
+    /// cause spurious code-5 errors right at the boundary. Accepts
+    /// `0.0..1.0`; apply the same margin to a shared IP limiter via
+    /// [`IpRateLimiter::with_margin`].
+    pub fn rate_limit_margin(mut self, margin: f64) -> Result<Self> {
+        if !(0.0..1.0).contains(&margin) {
+            return Err(TornError::InvalidParams(format!(
+                "rate limit margin {margin} outside 0.0..1.0"
+            )));
+        }
+        self.rate_limit_margin = margin;
+        Ok(self)
+    }
+
+    /// Installs a retry policy: transient failures (timeouts, error 5,
+    /// temporary backend errors) are retried with delays chosen by the given
+    /// [`crate::backoff::Backoff`]. Without one the client never retries.
+    /// [`crate::ExponentialBackoff::default`] is a sensible starting point.
+    pub fn retry_backoff(mut self, backoff: Arc<dyn crate::backoff::Backoff>) -> Self {
+        self.retry_backoff = Some(backoff);
+        self
+    }
+
+    /// Caps retries at this fraction of recent request volume (default 20%).
+    /// When the budget is spent, further retries are skipped and errors
+    /// surface immediately, so a brownout cannot trigger a retry storm.
+    pub fn retry_budget_ratio(mut self, ratio: f64) -> Self {
+        self.retry_budget_ratio = ratio;
+        self
+    }
+
+    /// Adds a query parameter sent with every request unless the call site
+    /// sets the same parameter itself, e.g. `("striptags", "true")`.
+    pub fn default_param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.default_params.push((name.into(), value.into()));
+        self
+    }
+
+    /// Like [`TornClientConfig::default_param`], but only for one API section
+    /// (`"user"`, `"faction"`, ...), e.g. `("user", "limit", "100")`.
+    pub fn endpoint_default_param(
+        mut self,
+        endpoint: impl Into<String>,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.endpoint_default_params
+            .entry(endpoint.into())
+            .or_default()
+            .push((name.into(), value.into()));
+        self
+    }
+}
+
+/// Shared state behind a [`TornClient`]; cloning the client is cheap and all
+/// clones share one connection pool, key pool and rate limiter.
+#[derive(Debug)]
+pub(crate) struct ClientInner {
+    pub(crate) http: reqwest::Client,
+    pub(crate) config: TornClientConfig,
+    pub(crate) keys: ApiKeyPool,
+    pub(crate) limiter: Arc<dyn crate::rate_limit::RateLimit>,
+    pub(crate) slow_requests: AtomicU64,
+    pub(crate) paused: AtomicBool,
+    pub(crate) resume_notify: Notify,
+    pub(crate) capabilities: tokio::sync::OnceCell<KeyCapabilities>,
+    pub(crate) log_catalog: tokio::sync::OnceCell<crate::catalog::LogCatalog>,
+    pub(crate) item_catalog: tokio::sync::OnceCell<crate::catalog::ItemCatalog>,
+    pub(crate) crimes: tokio::sync::OnceCell<Vec<crate::models::torn::TornCrime>>,
+    pub(crate) honors: tokio::sync::OnceCell<Vec<crate::models::torn::Honor>>,
+    pub(crate) medals: tokio::sync::OnceCell<Vec<crate::models::torn::Medal>>,
+    pub(crate) health: crate::health::HealthTracker,
+    pub(crate) cooloff_until: std::sync::Mutex<Option<Instant>>,
+    pub(crate) clock_skew_secs: std::sync::atomic::AtomicI64,
+    pub(crate) clock_synced: AtomicBool,
+    pub(crate) retry_budget: crate::backoff::RetryBudget,
+    pub(crate) usage: crate::usage::UsageTracker,
+    pub(crate) shutting_down: AtomicBool,
+    pub(crate) in_flight: AtomicU64,
+    pub(crate) drain_notify: Notify,
+}
+
+/// Client for the Torn v2 API.
+///
+/// Internally `Arc`-based: clone freely and move clones into spawned tasks.
+/// Endpoint handles obtained via [`TornClient::user`], [`TornClient::faction`]
+/// etc. own such a clone and are therefore `Send + 'static` themselves.
+#[derive(Clone)]
+pub struct TornClient {
+    pub(crate) inner: Arc<ClientInner>,
+    // Per-handle override; see `TornClient::with_rate_limit_mode`.
+    pub(crate) rate_limit_mode_override: Option<RateLimitMode>,
+    // Per-handle pinned key; see `TornClient::with_key`.
+    pub(crate) key_override: Option<String>,
+}
+
+impl std::fmt::Debug for TornClient {
+    // Manual so a pinned handle's raw key stays out of diagnostics; the
+    // shared state redacts itself.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TornClient")
+            .field("inner", &self.inner)
+            .field("rate_limit_mode_override", &self.rate_limit_mode_override)
+            .field(
+                "key_override",
+                &self.key_override.as_deref().map(redact_key),
+            )
+            .finish()
+    }
+}
+
+impl TornClient {
+    /// Constructs a client configured from `TORN_*` environment variables;
+    /// see [`TornClientConfig::from_env`] for the recognized set.
+    pub fn from_env() -> Result<Self> {
+        Ok(Self::new(TornClientConfig::from_env()?))
+    }
+
+    /// Constructs a client from the given configuration.
+    pub fn new(config: TornClientConfig) -> Self {
+        let keys = ApiKeyPool::new(config.keys.iter().cloned());
+        for (key, label) in config.key_labels.iter() {
+            keys.set_label(key, label);
+        }
+        let limiter = config.rate_limiter.clone().unwrap_or_else(|| {
+            Arc::new(RateLimiter::with_limit(crate::rate_limit::margined_limit(
+                crate::rate_limit::REQUESTS_PER_MINUTE,
+                config.rate_limit_margin,
+            )))
+        });
+        if let Some(snapshot) = config.rate_limit_state.clone() {
+            limiter.restore(snapshot);
+        }
+        let retry_budget_ratio = config.retry_budget_ratio;
+        let http = config.http_client.clone().unwrap_or_else(|| {
+            let mut builder = reqwest::Client::builder()
+                .user_agent(config.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT));
+            if let Some(timeout) = config.timeout {
+                builder = builder.timeout(timeout);
+            }
+            if let Some(connect_timeout) = config.connect_timeout {
+                builder = builder.connect_timeout(connect_timeout);
+            }
+            // Pool and keepalive knobs are connection-level and do not exist
+            // on reqwest's wasm (fetch) backend.
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                if let Some(idle) = config.pool_idle_timeout {
+                    builder = builder.pool_idle_timeout(idle);
+                }
+                if let Some(max) = config.pool_max_idle_per_host {
+                    builder = builder.pool_max_idle_per_host(max);
+                }
+                if let Some(interval) = config.tcp_keepalive {
+                    builder = builder.tcp_keepalive(interval);
+                }
+            }
+            #[cfg(feature = "compression")]
+            {
+                builder = builder.gzip(config.compression).brotli(config.compression);
+            }
+            if let Some(url) = &config.proxy {
+                // Validated in `TornClientConfig::proxy`.
+                let mut proxy = reqwest::Proxy::all(url).expect("proxy url validated at build");
+                if let Some((username, password)) = &config.proxy_auth {
+                    proxy = proxy.basic_auth(username, password);
+                }
+                builder = builder.proxy(proxy);
+            }
+            builder.build().expect("default reqwest client builds")
+        });
+        Self {
+            inner: Arc::new(ClientInner {
+                http,
+                config,
+                keys,
+                limiter,
+                slow_requests: AtomicU64::new(0),
+                paused: AtomicBool::new(false),
+                resume_notify: Notify::new(),
+                capabilities: tokio::sync::OnceCell::new(),
+                log_catalog: tokio::sync::OnceCell::new(),
+                item_catalog: tokio::sync::OnceCell::new(),
+                crimes: tokio::sync::OnceCell::new(),
+                honors: tokio::sync::OnceCell::new(),
+                medals: tokio::sync::OnceCell::new(),
+                health: crate::health::HealthTracker::default(),
+                cooloff_until: std::sync::Mutex::new(None),
+                clock_skew_secs: std::sync::atomic::AtomicI64::new(0),
+                clock_synced: AtomicBool::new(false),
+                retry_budget: crate::backoff::RetryBudget::new(retry_budget_ratio),
+                usage: crate::usage::UsageTracker::default(),
+                shutting_down: AtomicBool::new(false),
+                in_flight: AtomicU64::new(0),
+                drain_notify: Notify::new(),
+            }),
+            rate_limit_mode_override: None,
+            key_override: None,
+        }
+    }
+
+    /// The configuration this client was built from.
+    pub fn config(&self) -> &TornClientConfig {
+        &self.inner.config
+    }
+
+    /// A handle whose requests use `mode` instead of the configured rate
+    /// limit mode, sharing every other piece of state (key pool, limiter
+    /// windows, connection pool) with this client. Lets an interactive
+    /// command fail fast with [`RateLimitMode::Error`] while background jobs
+    /// on the same client keep the default [`RateLimitMode::AutoDelay`].
+    pub fn with_rate_limit_mode(&self, mode: RateLimitMode) -> TornClient {
+        TornClient {
+            inner: self.inner.clone(),
+            rate_limit_mode_override: Some(mode),
+            key_override: self.key_override.clone(),
+        }
+    }
+
+    /// A handle whose requests bypass the key rotation and always use `key`,
+    /// sharing every other piece of state with this client. Useful when one
+    /// pool key has faction API access: pin it for `/faction/*` calls while
+    /// the rotation keeps serving everything else. The pinned key still
+    /// draws from its own rate limit window.
+    pub fn with_key(&self, key: impl Into<String>) -> TornClient {
+        TornClient {
+            inner: self.inner.clone(),
+            rate_limit_mode_override: self.rate_limit_mode_override,
+            key_override: Some(key.into()),
+        }
+    }
+
+    /// A handle pinned to the pool key a stable hash of `name` selects, so
+    /// one logical workload — a war-target watcher, a market scanner —
+    /// keeps hitting the same key while other sessions use other keys.
+    /// That keeps Torn's per-key server-side caches and logs coherent for
+    /// the workload. The same name always maps to the same key while the
+    /// pool is unchanged; with an empty pool the handle is unpinned and
+    /// requests fail with [`crate::TornError::NoKeyAvailable`] as usual.
+    pub fn session(&self, name: &str) -> TornClient {
+        use std::hash::{Hash, Hasher};
+
+        let keys = self.inner.keys.keys();
+        let mut handle = self.clone();
+        if !keys.is_empty() {
+            // DefaultHasher::new() is fixed-seed, so the mapping is stable
+            // across handles and process restarts alike.
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            name.hash(&mut hasher);
+            handle.key_override = Some(keys[hasher.finish() as usize % keys.len()].clone());
+        }
+        handle
+    }
+
+    /// The rate limit mode in effect for requests through this handle.
+    pub(crate) fn rate_limit_mode(&self) -> RateLimitMode {
+        self.rate_limit_mode_override
+            .unwrap_or(self.inner.config.rate_limit_mode)
+    }
+
+    /// Number of requests so far that exceeded the slow-request threshold.
+    pub fn slow_request_count(&self) -> u64 {
+        self.inner.slow_requests.load(Ordering::Relaxed)
+    }
+
+    /// Stops traffic without tearing the client down. Affects all clones of
+    /// this client; behavior of new requests follows the configured
+    /// [`PauseMode`].
+    pub fn pause(&self) {
+        self.inner.paused.store(true, Ordering::SeqCst);
+    }
+
+    /// Lifts a [`TornClient::pause`], releasing any waiting requests.
+    pub fn resume(&self) {
+        self.inner.paused.store(false, Ordering::SeqCst);
+        self.inner.resume_notify.notify_waiters();
+    }
+
+    /// Whether the client is currently paused.
+    pub fn is_paused(&self) -> bool {
+        self.inner.paused.load(Ordering::SeqCst)
+    }
+
+    /// Replaces the key pool with the keys found in `path` (one key per
+    /// line; blank lines and `#` comments are ignored). Returns how many keys
+    /// are now active.
+    pub fn reload_keys_from_file(
+        &self,
+        path: impl AsRef<std::path::Path>,
+    ) -> std::io::Result<usize> {
+        let contents = std::fs::read_to_string(path)?;
+        self.inner.keys.set_keys(parse_key_file(&contents));
+        Ok(self.inner.keys.len())
+    }
+
+    /// Spawns a background task that polls `path` every `poll_interval` and
+    /// applies additions/removals to the live key pool, so donated or revoked
+    /// faction keys take effect without a restart. The task exits when every
+    /// clone of this client has been dropped.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn watch_key_file(
+        &self,
+        path: impl Into<std::path::PathBuf>,
+        poll_interval: Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        let path = path.into();
+        let inner = Arc::downgrade(&self.inner);
+        tokio::spawn(async move {
+            let mut last_modified = None;
+            loop {
+                tokio::time::sleep(poll_interval).await;
+                let Some(inner) = inner.upgrade() else {
+                    break;
+                };
+                let modified = match std::fs::metadata(&path).and_then(|m| m.modified()) {
+                    Ok(modified) => modified,
+                    Err(e) => {
+                        tracing::warn!(path = %path.display(), error = %e, "key file unreadable");
+                        continue;
+                    }
+                };
+                if last_modified == Some(modified) {
+                    continue;
+                }
+                last_modified = Some(modified);
+                match std::fs::read_to_string(&path) {
+                    Ok(contents) => {
+                        inner.keys.set_keys(parse_key_file(&contents));
+                        tracing::info!(
+                            path = %path.display(),
+                            keys = inner.keys.len(),
+                            "reloaded api keys"
+                        );
+                    }
+                    Err(e) => {
+                        tracing::warn!(path = %path.display(), error = %e, "key file unreadable");
+                    }
+                }
+            }
+        })
+    }
+
+    /// Serializes the rate limiter's current window state for restoring
+    /// after a restart via [`TornClientConfig::rate_limit_state`]. `None`
+    /// when the installed limiter keeps no local state.
+    pub fn rate_limit_snapshot(&self) -> Option<crate::rate_limit::RateLimiterSnapshot> {
+        self.inner.limiter.snapshot()
+    }
+
+    /// Writes the rate limiter state as JSON to `path`, typically right
+    /// before process exit. Returns `false` (writing nothing) when the
+    /// limiter has no state to persist. The file contains the pool's API
+    /// keys — treat it like a key file.
+    pub fn save_rate_limit_state(
+        &self,
+        path: impl AsRef<std::path::Path>,
+    ) -> std::io::Result<bool> {
+        let Some(snapshot) = self.rate_limit_snapshot() else {
+            return Ok(false);
+        };
+        let json = serde_json::to_vec(&snapshot).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)?;
+        Ok(true)
+    }
+
+    /// Keys pulled from rotation after the server rejected them as
+    /// invalid; see [`TornClientConfig::on_key_invalidated`]. Re-adding
+    /// one via [`TornClient::add_key`] lifts its quarantine.
+    pub fn quarantined_keys(&self) -> Vec<String> {
+        self.inner.keys.quarantined()
+    }
+
+    /// Adds an API key to the shared pool at runtime, so a long-running
+    /// service can rotate in fresh credentials without reconstructing the
+    /// client. Returns `false` when the key is empty or already pooled.
+    pub fn add_key(&self, key: impl Into<String>) -> bool {
+        self.inner.keys.add_key(key)
+    }
+
+    /// Removes every pool key starting with `prefix` — pass a full key, or
+    /// the redacted prefix as it appears in logs — and drops the rate
+    /// limiter's accounting for them. Returns how many keys were removed;
+    /// an empty prefix removes nothing.
+    pub fn remove_key(&self, prefix: &str) -> usize {
+        let removed = self.inner.keys.remove_keys(prefix);
+        for key in &removed {
+            self.inner.limiter.forget(key);
+        }
+        removed.len()
+    }
+
+    /// Earmarks `amount` request slots from the shared limiter for one
+    /// task — say a 40-page attack backfill — until the guard is dropped or
+    /// `ttl` elapses. Other traffic sees correspondingly reduced headroom;
+    /// tag the task's own calls with [`RequestOptions::reservation`] to
+    /// draw from the earmark. `None` when the installed limiter does not
+    /// support reservations.
+    pub fn reserve_budget(&self, amount: u32, ttl: Duration) -> Option<BudgetReservation> {
+        let id = self.inner.limiter.reserve(amount, ttl)?;
+        Some(BudgetReservation::new(
+            Arc::clone(&self.inner.limiter),
+            id,
+            amount,
+        ))
+    }
+
+    /// Point-in-time budget view per pool key — used slots, remaining
+    /// slots and any saturation penalty — for dashboards and schedulers.
+    /// Keys that have not sent a request yet are absent; custom limiters
+    /// without local accounting yield an empty map.
+    pub async fn rate_limit_status(
+        &self,
+    ) -> std::collections::HashMap<String, crate::rate_limit::RateLimitInfo> {
+        let mut status = self.inner.limiter.status().await;
+        for (key, info) in status.iter_mut() {
+            info.label = self.inner.keys.label(key);
+        }
+        status
+    }
+
+    /// Slots left in the shared IP-wide window, or `None` when no limiter
+    /// was registered via [`TornClientConfig::ip_limiter`].
+    pub async fn ip_remaining(&self) -> Option<u32> {
+        match &self.inner.config.ip_limiter {
+            Some(limiter) => Some(limiter.remaining().await),
+            None => None,
+        }
+    }
+
+    /// Requests currently being executed across all clones of this client.
+    pub fn in_flight_requests(&self) -> u64 {
+        self.inner.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Whether [`TornClient::shutdown`] has been called on any clone of this
+    /// client. Once true, every new request fails with
+    /// [`TornError::ShutDown`].
+    pub fn is_shut_down(&self) -> bool {
+        self.inner.shutting_down.load(Ordering::SeqCst)
+    }
+
+    /// Stops accepting new requests and waits up to `timeout` for in-flight
+    /// ones to finish. New requests fail with [`TornError::ShutDown`] from
+    /// the moment this is called; the shutdown is permanent for this client
+    /// and all of its clones. Connections are released when the last clone
+    /// is dropped.
+    pub async fn shutdown(&self, timeout: Duration) -> ShutdownReport {
+        self.inner.shutting_down.store(true, Ordering::SeqCst);
+        let deadline = Instant::now() + timeout;
+        while self.in_flight_requests() > 0 {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+            let notified = self.inner.drain_notify.notified();
+            if self.in_flight_requests() == 0 {
+                break;
+            }
+            // select instead of tokio::time::timeout so this compiles for
+            // wasm, where tokio's timer is unavailable.
+            futures_util::future::select(
+                std::pin::pin!(notified),
+                std::pin::pin!(crate::compat::sleep(remaining)),
+            )
+            .await;
+        }
+        let aborted = self.in_flight_requests();
+        ShutdownReport {
+            drained: aborted == 0,
+            aborted_in_flight: aborted,
+        }
+    }
+
+    /// Blocks (or fails) while the client is paused, per the configured mode.
+    async fn wait_if_paused(&self) -> Result<()> {
+        while self.is_paused() {
+            if self.inner.config.pause_mode == PauseMode::Error {
+                return Err(TornError::Paused);
+            }
+            // Register for the notification before re-checking so a resume
+            // between the check and the await cannot be missed.
+            let notified = self.inner.resume_notify.notified();
+            if !self.is_paused() {
+                break;
+            }
+            notified.await;
+        }
+        Ok(())
+    }
+
+    /// How much of an IP-block cool-off is still ahead, if one is active.
+    pub fn cooloff_remaining(&self) -> Option<Duration> {
+        let until = (*self.inner.cooloff_until.lock().expect("cooloff poisoned"))?;
+        until.checked_duration_since(Instant::now())
+    }
+
+    /// Blocks (or fails) while an IP-block cool-off is active, per the
+    /// configured pause mode.
+    async fn wait_if_cooling_off(&self) -> Result<()> {
+        while let Some(remaining) = self.cooloff_remaining() {
+            if self.inner.config.pause_mode == PauseMode::Error {
+                return Err(TornError::CoolingOff { remaining });
+            }
+            crate::compat::sleep(remaining).await;
+        }
+        Ok(())
+    }
+
+    /// Freezes all outgoing traffic for the configured cool-off after an
+    /// IP-block response. Keeps the latest deadline if blocks overlap.
+    fn begin_ip_block_cooloff(&self) {
+        let cooloff = self.inner.config.ip_block_cooloff;
+        let until = Instant::now() + cooloff;
+        let mut slot = self.inner.cooloff_until.lock().expect("cooloff poisoned");
+        if slot.is_none_or(|current| current < until) {
+            *slot = Some(until);
+        }
+        tracing::warn!(
+            cooloff_secs = cooloff.as_secs(),
+            "torn api reported an ip block; freezing all outgoing requests"
+        );
+    }
+
+    /// Handle for the `/user` section.
+    pub fn user(&self) -> UserEndpoint {
+        UserEndpoint::new(self.clone())
+    }
+
+    /// Handle for the `/faction` section.
+    pub fn faction(&self) -> FactionEndpoint {
+        FactionEndpoint::new(self.clone())
+    }
+
+    /// Handle for the `/market` section.
+    pub fn market(&self) -> MarketEndpoint {
+        MarketEndpoint::new(self.clone())
+    }
+
+    /// Handle for the `/torn` section.
+    pub fn torn(&self) -> TornEndpoint {
+        TornEndpoint::new(self.clone())
+    }
+
+    /// Handle for the `/racing` section.
+    pub fn racing(&self) -> RacingEndpoint {
+        RacingEndpoint::new(self.clone())
+    }
+
+    /// Handle for the `/key` section.
+    pub fn key(&self) -> KeyEndpoint {
+        KeyEndpoint::new(self.clone())
+    }
+
+    /// Handle for the legacy v1 API, for selections that have not been
+    /// migrated to v2 yet. Shares this client's key pool and rate limiter.
+    pub fn v1(&self) -> crate::v1::V1Endpoint {
+        crate::v1::V1Endpoint::new(self.clone())
+    }
+
+    /// The v1 origin corresponding to the configured (v2) base URL.
+    pub(crate) fn v1_base_url(&self) -> String {
+        let base = self.inner.config.base_url.trim_end_matches('/');
+        base.strip_suffix("/v2").unwrap_or(base).to_owned()
+    }
+
+    /// What the key in use is allowed to request, fetched from `/key/info`
+    /// on first call and cached for the lifetime of the client.
+    pub async fn key_capabilities(&self) -> Result<&KeyCapabilities> {
+        self.note_cache_hit(self.inner.capabilities.initialized());
+        self.inner
+            .capabilities
+            .get_or_try_init(|| async {
+                let info = self.key().info().await?;
+                Ok(KeyCapabilities::from_info(info))
+            })
+            .await
+    }
+
+    /// Calls `/key/info` for every pool key concurrently — each check runs
+    /// on its own key's budget, so the sweep is rate-aware — and reports
+    /// what each key can access. Run it at startup to fail a deploy on bad
+    /// credentials instead of failing intermittently later:
+    ///
+    /// ```no_run
+    /// # async fn run(client: torn_client::TornClient) {
+    /// let report = client.validate_keys().await;
+    /// assert!(report.all_valid(), "bad keys: {:?}", report.invalid());
+    /// # }
+    /// ```
+    pub async fn validate_keys(&self) -> KeyValidationReport {
+        let keys = self.inner.keys.keys();
+        let checks = keys.iter().map(|key| {
+            let handle = self.with_key(key.clone());
+            async move { handle.key().info().await }
+        });
+        let results = futures_util::future::join_all(checks).await;
+        KeyValidationReport {
+            keys: keys
+                .into_iter()
+                .zip(results)
+                .map(|(key, info)| KeyValidation {
+                    label: self.inner.keys.label(&key),
+                    key: redact_key(&key),
+                    info,
+                })
+                .collect(),
+        }
+    }
+
+    /// Fetches one page of an offset/limit endpoint that reports no
+    /// `_metadata.links` (`/key/log`, some market selections) and
+    /// synthesizes the navigation from the request parameters and page
+    /// fullness: `next` exists when the page came back full, `prev` when
+    /// `offset` is positive. The synthesized links are ordinary absolute
+    /// URLs, so every pagination adapter — streams, `collect_all`, stored
+    /// cursors — works unchanged. Endpoints that do report links keep them.
+    pub async fn get_offset_paged<T>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+        offset: u64,
+        limit: u64,
+    ) -> Result<crate::pagination::PaginatedResponse<T>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let mut full_query: Vec<(&str, String)> = query
+            .iter()
+            .filter(|(name, _)| *name != "offset" && *name != "limit")
+            .cloned()
+            .collect();
+        full_query.push(("limit", limit.to_string()));
+        full_query.push(("offset", offset.to_string()));
+        let envelope: crate::pagination::PagedEnvelope<T> = self.get(path, &full_query).await?;
+        let (data, mut metadata) = envelope.into_data();
+        let base = format!("{}{}", self.inner.config.base_url, path);
+        if metadata.links.next.is_none() && limit > 0 && data.len() as u64 >= limit {
+            metadata.links.next = Some(crate::pagination::synthesized_offset_link(
+                &base,
+                query,
+                offset + limit,
+                limit,
+            ));
+        }
+        if metadata.links.prev.is_none() && offset > 0 {
+            metadata.links.prev = Some(crate::pagination::synthesized_offset_link(
+                &base,
+                query,
+                offset.saturating_sub(limit),
+                limit,
+            ));
+        }
+        metadata.limit.get_or_insert(limit);
+        metadata.offset.get_or_insert(offset);
+        Ok(crate::pagination::PaginatedResponse::new(
+            data,
+            metadata,
+            self.clone(),
+        ))
+    }
+
+    /// Resumes a paginated walk from a cursor stored in an earlier run; see
+    /// [`crate::pagination::PageCursor`]. The fetched page carries fresh
+    /// navigation links, so every pagination adapter works from here:
+    ///
+    /// ```no_run
+    /// # async fn run(client: torn_client::TornClient, stored: String)
+    /// # -> Result<(), torn_client::TornError> {
+    /// let cursor: torn_client::PageCursor = stored.parse()?;
+    /// let page = client
+    ///     .resume_from::<torn_client::models::user::Attack>(&cursor)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resume_from<T>(
+        &self,
+        cursor: &crate::pagination::PageCursor,
+    ) -> Result<crate::pagination::PaginatedResponse<T>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let envelope: crate::pagination::PagedEnvelope<T> =
+            self.get_url(cursor.as_str(), &[]).await?;
+        let (data, metadata) = envelope.into_data();
+        Ok(crate::pagination::PaginatedResponse::new(
+            data,
+            metadata,
+            self.clone(),
+        ))
+    }
+
+    /// Fetches a page straight from a raw link captured earlier — the
+    /// string form of [`crate::pagination::PaginatedResponse::next_url`] —
+    /// so pagination state can be handed between services without going
+    /// through [`crate::pagination::PageCursor`] first. The URL is
+    /// validated the same way a cursor would be.
+    pub async fn fetch_page<T>(&self, url: &str) -> Result<crate::pagination::PaginatedResponse<T>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let cursor: crate::pagination::PageCursor = url.parse()?;
+        self.resume_from(&cursor).await
+    }
+
+    /// The log type/category catalog, fetched from `/torn/logtypes` and
+    /// `/torn/logcategories` on first call and cached for the lifetime of
+    /// the client.
+    pub async fn log_catalog(&self) -> Result<&crate::catalog::LogCatalog> {
+        self.note_cache_hit(self.inner.log_catalog.initialized());
+        self.inner
+            .log_catalog
+            .get_or_try_init(|| async {
+                let torn = self.torn();
+                let types = torn.log_types().await?;
+                let categories = torn.log_categories().await?;
+                Ok(crate::catalog::LogCatalog::new(types, categories))
+            })
+            .await
+    }
+
+    /// Resolves DNS and performs the TCP/TLS handshake to the API host ahead
+    /// of time, so the first real request does not pay connection setup
+    /// latency. Sends one keyless `HEAD` to the host root; it spends no key
+    /// budget and the response status is irrelevant.
+    pub async fn warmup(&self) -> Result<()> {
+        let origin = url::Url::parse(&self.inner.config.base_url)
+            .ok()
+            .and_then(|parsed| parsed.join("/").ok())
+            .map(String::from)
+            .unwrap_or_else(|| self.inner.config.base_url.clone());
+        self.inner.http.head(origin).send().await?;
+        Ok(())
+    }
+
+    /// The indexed item catalog, fetched from `/torn/items` on first call and
+    /// cached for the lifetime of the client.
+    pub async fn item_catalog(&self) -> Result<&crate::catalog::ItemCatalog> {
+        self.note_cache_hit(self.inner.item_catalog.initialized());
+        self.inner
+            .item_catalog
+            .get_or_try_init(|| async {
+                let items = self.torn().items().await?;
+                Ok(crate::catalog::ItemCatalog::new(items))
+            })
+            .await
+    }
+
+    /// Fetches `/torn/timestamp` and records the offset between the server
+    /// clock and the local one. Returns the observed skew in seconds
+    /// (positive when the server is ahead). Call once at startup — or
+    /// periodically from long-lived processes — so [`TornClient::torn_now`]
+    /// stays accurate despite local clock drift.
+    pub async fn sync_clock(&self) -> Result<i64> {
+        self.torn().timestamp().await?;
+        Ok(self.inner.clock_skew_secs.load(Ordering::Relaxed))
+    }
+
+    /// The current Torn server time as a unix timestamp, corrected by the
+    /// last observed clock skew. Chain timers and cooldown math should use
+    /// this instead of the local clock, which can be seconds off.
+    pub fn torn_now(&self) -> i64 {
+        local_unix_now() + self.inner.clock_skew_secs.load(Ordering::Relaxed)
+    }
+
+    /// The last observed server-minus-local skew in seconds, or `None` before
+    /// the first [`TornClient::sync_clock`] (or `/torn/timestamp`) call.
+    pub fn clock_skew(&self) -> Option<i64> {
+        self.inner
+            .clock_synced
+            .load(Ordering::Relaxed)
+            .then(|| self.inner.clock_skew_secs.load(Ordering::Relaxed))
+    }
+
+    /// Updates the skew estimate from a server timestamp just received.
+    pub(crate) fn record_server_time(&self, server: i64) -> i64 {
+        let skew = server - local_unix_now();
+        self.inner.clock_skew_secs.store(skew, Ordering::Relaxed);
+        self.inner.clock_synced.store(true, Ordering::Relaxed);
+        skew
+    }
+
+    /// Aggregate usage since construction: requests per endpoint and per
+    /// (redacted) key, API error counts by code, cache hits, and accumulated
+    /// rate-limit wait time. Ideal for printing at bot shutdown.
+    pub fn usage_report(&self) -> crate::usage::UsageReport {
+        self.inner.usage.report()
+    }
+
+    /// Per-key request and failure counters since construction, keyed by
+    /// redacted key. Spots a misbehaving pool member — say one donated key
+    /// returning code 16 half the time — that aggregate counts would hide.
+    pub fn key_stats(&self) -> HashMap<String, crate::usage::KeyStats> {
+        self.inner.usage.key_stats()
+    }
+
+    /// Counts one one-time-cache lookup when it was served without a fetch.
+    fn note_cache_hit(&self, was_cached: bool) {
+        if was_cached {
+            self.inner.usage.record_cache_hit();
+        }
+    }
+
+    /// A verdict on the API's recent health (rolling two-minute window of
+    /// request outcomes), with a per-kind error breakdown. Schedulers should
+    /// consult this and back off globally when it reports
+    /// [`crate::ApiHealth::Down`] rather than letting every job fail on its
+    /// own.
+    pub fn api_status(&self) -> crate::health::ApiStatus {
+        self.inner.health.status()
+    }
+
+    /// A handle over the game's static reference data (items, crimes, honors,
+    /// medals). Each dataset is fetched once per client lifetime, no matter
+    /// how many subsystems await it concurrently.
+    pub fn static_data(&self) -> StaticData<'_> {
+        StaticData { client: self }
+    }
+
+    /// Whether the key can request `selection` from `section`, e.g.
+    /// `("faction", "attacks")`. Fetches and caches capabilities on first use.
+    pub async fn can_access(&self, section: &str, selection: &str) -> Result<bool> {
+        Ok(self
+            .key_capabilities()
+            .await?
+            .can_access(section, selection))
+    }
+
+    /// Performs a GET against `path` (relative to the base URL) with the given
+    /// query parameters, deserializing the response into `T`.
+    ///
+    /// This is the escape hatch the typed endpoint handles are built on:
+    /// new or obscure v2 endpoints the crate does not cover yet can be hit
+    /// directly, with the same key rotation, rate limiting and retry
+    /// behavior.
+    ///
+    /// ```no_run
+    /// # async fn run(client: torn_client::TornClient) -> torn_client::Result<()> {
+    /// #[derive(serde::Deserialize)]
+    /// struct Education { complete: Vec<u64> }
+    /// let education: Education = client.get("/user/education", &[]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+    ) -> Result<T> {
+        self.check_preflight_access(path)?;
+        let url = format!("{}{}", self.inner.config.base_url, path);
+        let query = self.apply_default_params(path, query);
+        self.get_url(&url, &query).await
+    }
+
+    /// Like [`TornClient::get`] with per-request tweaks: extra parameters, a
+    /// call-specific timeout, one-off headers or a cache-bypass timestamp.
+    /// Only the initial request is affected; pagination links followed from
+    /// the response use the client-wide settings again.
+    pub async fn get_with_options<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+        options: &RequestOptions,
+    ) -> Result<T> {
+        self.check_preflight_access(path)?;
+        let url = format!("{}{}", self.inner.config.base_url, path);
+        let mut query = self.apply_default_params(path, query);
+        for (name, value) in &options.params {
+            if !query.iter().any(|(existing, _)| existing == name) {
+                query.push((name.clone(), value.clone()));
+            }
+        }
+        if options.bypass_cache {
+            query.push(("timestamp".to_owned(), self.torn_now().to_string()));
+        }
+        self.get_url_auth(&url, &query, KeyAuth::Header, options)
+            .await
+    }
+
+    /// Like [`TornClient::get`] but also returns the exchange's
+    /// [`ResponseMeta`] — status, headers, latency, serving key — for
+    /// callers debugging a slow endpoint or attributing usage per key:
+    ///
+    /// ```no_run
+    /// # async fn run(client: torn_client::TornClient) -> torn_client::Result<()> {
+    /// let envelope = client
+    ///     .get_enveloped::<serde_json::Value>("/user/profile", &[])
+    ///     .await?;
+    /// println!("{} in {:?}", envelope.meta.status, envelope.meta.elapsed);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_enveloped<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+    ) -> Result<ResponseEnvelope<T>> {
+        let sink = MetaSink::default();
+        let options = RequestOptions {
+            meta_sink: Some(sink.clone()),
+            ..RequestOptions::default()
+        };
+        let data = self.get_with_options(path, query, &options).await?;
+        let meta = sink
+            .0
+            .lock()
+            .expect("meta sink poisoned")
+            .take()
+            .expect("successful request always records meta");
+        Ok(ResponseEnvelope { data, meta })
+    }
+
+    /// Like [`TornClient::get`] but returns the raw JSON body, for endpoints
+    /// whose shape is unknown or still changing.
+    pub async fn get_raw(&self, path: &str, query: &[(&str, String)]) -> Result<serde_json::Value> {
+        self.get(path, query).await
+    }
+
+    /// Fails fast when the guard is enabled, capabilities are already cached,
+    /// and the key cannot serve this path. With capabilities not yet fetched
+    /// the request proceeds unchecked rather than triggering a hidden fetch.
+    fn check_preflight_access(&self, path: &str) -> Result<()> {
+        if !self.inner.config.preflight_access_check {
+            return Ok(());
+        }
+        let Some(capabilities) = self.inner.capabilities.get() else {
+            return Ok(());
+        };
+        let mut segments = path.trim_start_matches('/').split('/');
+        let (Some(section), Some(selection)) = (segments.next(), segments.next_back()) else {
+            return Ok(());
+        };
+        if capabilities.can_access(section, selection) {
+            return Ok(());
+        }
+        Err(TornError::InsufficientAccess {
+            required: format!("{section}/{selection}"),
+            actual: capabilities.access_level(),
+        })
+    }
+
+    /// Merges configured default parameters into an explicit query, with the
+    /// explicit parameters winning on conflicts.
+    fn apply_default_params(&self, path: &str, query: &[(&str, String)]) -> Vec<(String, String)> {
+        let config = &self.inner.config;
+        let section = path.trim_start_matches('/').split('/').next().unwrap_or("");
+        let mut merged: Vec<(String, String)> = query
+            .iter()
+            .map(|(name, value)| ((*name).to_owned(), value.clone()))
+            .collect();
+        let defaults = config.default_params.iter().chain(
+            config
+                .endpoint_default_params
+                .get(section)
+                .into_iter()
+                .flatten(),
+        );
+        for (name, value) in defaults {
+            if !merged.iter().any(|(existing, _)| existing == name) {
+                merged.push((name.clone(), value.clone()));
+            }
+        }
+        if let Some(comment) = &config.comment {
+            if !merged.iter().any(|(name, _)| name == "comment") {
+                merged.push(("comment".to_owned(), comment.clone()));
+            }
+        }
+        merged
+    }
+
+    /// Like [`TornClient::get`] but takes an absolute URL, as returned in
+    /// pagination `_metadata` links.
+    pub(crate) async fn get_url<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        query: &[(String, String)],
+    ) -> Result<T> {
+        self.get_url_auth(url, query, KeyAuth::Header, &RequestOptions::default())
+            .await
+    }
+
+    /// Like [`TornClient::get_url`] with an explicit key-auth style, so the
+    /// legacy v1 API (`key=` query parameter) shares the same key rotation,
+    /// rate limiting and retry path.
+    pub(crate) async fn get_url_auth<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        query: &[(String, String)],
+        auth: KeyAuth,
+        options: &RequestOptions,
+    ) -> Result<T> {
+        let assigned;
+        let options = match (&self.inner.config.request_id_header, &options.request_id) {
+            (Some(_), None) => {
+                assigned = RequestOptions {
+                    request_id: Some(next_request_id()),
+                    ..options.clone()
+                };
+                &assigned
+            }
+            _ => options,
+        };
+        let token = options.cancellation_token.as_ref().or(self
+            .inner
+            .config
+            .cancellation_token
+            .as_ref());
+        let result = match token {
+            None => self.get_url_retrying(url, query, auth, options).await,
+            // select instead of tokio::select! so this compiles without
+            // tokio's macros feature; the cancellation arm wins ties, so an
+            // already cancelled token never sends anything.
+            Some(token) => match futures_util::future::select(
+                std::pin::pin!(token.cancelled()),
+                std::pin::pin!(self.get_url_retrying(url, query, auth, options)),
+            )
+            .await
+            {
+                futures_util::future::Either::Left(_) => Err(TornError::Cancelled),
+                futures_util::future::Either::Right((result, _)) => result,
+            },
+        };
+        match (result, &options.request_id) {
+            (Err(error), Some(id)) => Err(TornError::WithRequestId {
+                id: id.clone(),
+                source: Box::new(error),
+            }),
+            (result, _) => result,
+        }
+    }
+
+    /// The retry loop of [`TornClient::get_url_auth`], split out so
+    /// cancellation can race the whole thing at once.
+    async fn get_url_retrying<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        query: &[(String, String)],
+        auth: KeyAuth,
+        options: &RequestOptions,
+    ) -> Result<T> {
+        let mut attempt = 0u32;
+        self.inner.retry_budget.record_request();
+        loop {
+            match self.get_url_once(url, query, auth, options).await {
+                Ok(value) => return Ok(value),
+                Err(error) => {
+                    attempt += 1;
+                    // A key-invalid failure already quarantined the key;
+                    // retry immediately on the next pool key — the failure
+                    // is key-specific, so no backoff is warranted. The
+                    // shrinking pool bounds this: once it empties the
+                    // request fails with `NoKeyAvailable`.
+                    if error
+                        .api_code()
+                        .is_some_and(crate::error::codes::is_key_invalid)
+                        && self.key_override.is_none()
+                        && !self.inner.keys.is_empty()
+                    {
+                        continue;
+                    }
+                    let Some(policy) = &self.inner.config.retry_backoff else {
+                        return Err(error);
+                    };
+                    if !error.is_transient() {
+                        return Err(error);
+                    }
+                    let Some(delay) = policy.next_delay(attempt, &error) else {
+                        return Err(error);
+                    };
+                    if !self.inner.retry_budget.try_withdraw() {
+                        tracing::warn!(
+                            attempt,
+                            "retry budget exhausted; surfacing transient failure"
+                        );
+                        return Err(error);
+                    }
+                    tracing::debug!(
+                        attempt,
+                        delay_ms = delay.as_millis() as u64,
+                        error = %error,
+                        "retrying transient torn api failure"
+                    );
+                    crate::compat::sleep(delay).await;
+                }
+            }
+        }
+    }
+
+    /// How a key appears in logs and telemetry: its label plus the redacted
+    /// form when one was attached, just the redacted form otherwise.
+    fn key_display(&self, key: &str) -> String {
+        match self.inner.keys.label(key) {
+            Some(label) => format!("{label} ({})", redact_key(key)),
+            None => redact_key(key),
+        }
+    }
+
+    /// Picks the pool key for one request under the configured
+    /// [`ApiKeyBalancing`] strategy. The usage-aware strategies read the
+    /// limiter's per-key status; keys it has never seen count as untouched.
+    async fn pick_pool_key(&self) -> Option<String> {
+        let balancing = self.inner.config.key_balancing;
+        match balancing {
+            ApiKeyBalancing::RoundRobin => self.inner.keys.next_key(),
+            ApiKeyBalancing::Random => self.inner.keys.random_key(),
+            ApiKeyBalancing::LeastUsed | ApiKeyBalancing::LeastLoaded => {
+                let keys = self.inner.keys.keys();
+                let status = self.inner.limiter.status().await;
+                keys.into_iter().max_by_key(|key| match status.get(key) {
+                    None => u32::MAX,
+                    Some(info) if balancing == ApiKeyBalancing::LeastUsed => u32::MAX - info.used,
+                    Some(info) => info.remaining,
+                })
+            }
+        }
+    }
+
+    /// One attempt of [`TornClient::get_url`]: gates, key and slot
+    /// acquisition, then the exchange. Each retry re-enters from the top so a
+    /// different pool key can serve it.
+    async fn get_url_once<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        query: &[(String, String)],
+        auth: KeyAuth,
+        options: &RequestOptions,
+    ) -> Result<T> {
+        if self.inner.shutting_down.load(Ordering::SeqCst) {
+            return Err(TornError::ShutDown);
+        }
+        self.wait_if_paused().await?;
+        self.wait_if_cooling_off().await?;
+        let key = match &self.key_override {
+            Some(key) => key.clone(),
+            None => self
+                .pick_pool_key()
+                .await
+                .ok_or(TornError::NoKeyAvailable)?,
+        };
+        let mode = options
+            .rate_limit_mode
+            .unwrap_or_else(|| self.rate_limit_mode());
+        let limit_wait_started = Instant::now();
+        let refused_wait = std::sync::Mutex::new(None);
+        let on_wait = |wait: Duration| {
+            if let Some(ThrottleHook(hook)) = &self.inner.config.on_throttle {
+                hook(ThrottleEvent {
+                    key: redact_key(&key),
+                    url: url.to_owned(),
+                    wait,
+                });
+            }
+            match self.inner.config.max_rate_limit_wait {
+                Some(max) if wait > max => {
+                    *refused_wait.lock().expect("refused wait poisoned") = Some(wait);
+                    false
+                }
+                _ => true,
+            }
+        };
+        let context = AcquireContext {
+            priority: options.priority,
+            reservation: options.reservation,
+        };
+        if !self
+            .inner
+            .limiter
+            .acquire_with(&key, mode, context, &on_wait)
+            .await
+        {
+            let wait = *refused_wait.lock().expect("refused wait poisoned");
+            return Err(TornError::RateLimited { wait });
+        }
+        if let Some(ip_limiter) = &self.inner.config.ip_limiter {
+            if !ip_limiter.acquire_observed(mode, &on_wait).await {
+                let wait = *refused_wait.lock().expect("refused wait poisoned");
+                return Err(TornError::RateLimited { wait });
+            }
+        }
+        self.inner
+            .usage
+            .record_rate_limit_wait(limit_wait_started.elapsed());
+        self.inner.usage.record_request(url, &redact_key(&key));
+
+        let _guard = InFlightGuard::enter(&self.inner);
+        let result = self.send_and_decode(url, query, &key, auth, options).await;
+        match &result {
+            Ok(_) => self.inner.health.record_success(),
+            Err(error) => {
+                self.inner.health.record_error(error);
+                if let Some(code) = error.api_code() {
+                    self.inner.usage.record_api_error(code);
+                    self.inner
+                        .usage
+                        .record_key_api_error(&redact_key(&key), code);
+                } else if matches!(error, TornError::Http(_) | TornError::Timeout(_)) {
+                    self.inner
+                        .usage
+                        .record_key_transport_failure(&redact_key(&key));
+                }
+                if error.api_code() == Some(crate::error::codes::IP_BLOCK) {
+                    self.begin_ip_block_cooloff();
+                }
+                // The server knows better than local accounting: a code 5
+                // means this key is saturated, so mark it cold. With a retry
+                // backoff configured the retry loop re-enters on another
+                // pool key.
+                if error.api_code() == Some(crate::error::codes::TOO_MANY_REQUESTS) {
+                    self.inner.limiter.penalize(&key);
+                }
+                // A key the server rejects as invalid is pulled from the
+                // rotation entirely; the retry loop re-enters on another
+                // pool key. Pinned handles keep their key — the caller
+                // chose it explicitly.
+                if let Some(code) = error.api_code() {
+                    if crate::error::codes::is_key_invalid(code)
+                        && self.key_override.is_none()
+                        && self.inner.keys.quarantine(&key)
+                    {
+                        self.inner.limiter.forget(&key);
+                        tracing::warn!(
+                            key = %self.key_display(&key),
+                            code,
+                            "quarantined invalid api key"
+                        );
+                        if let Some(KeyInvalidatedHook(hook)) =
+                            &self.inner.config.on_key_invalidated
+                        {
+                            hook(KeyInvalidatedEvent {
+                                key: redact_key(&key),
+                                label: self.inner.keys.label(&key),
+                                code,
+                                message: error.to_string(),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// The HTTP exchange and decode step of [`TornClient::get_url`], split out
+    /// so every completed request feeds the health tracker exactly once.
+    async fn send_and_decode<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        query: &[(String, String)],
+        key: &str,
+        auth: KeyAuth,
+        options: &RequestOptions,
+    ) -> Result<T> {
+        let started = Instant::now();
+        let mut parts = crate::middleware::RequestParts {
+            url: url.to_owned(),
+            query: query.to_vec(),
+            headers: options.headers.clone(),
+        };
+        for middleware in &self.inner.config.middleware.0 {
+            middleware.before_request(&mut parts);
+        }
+        let mut request = self.inner.http.get(&parts.url).query(&parts.query);
+        request = match auth {
+            KeyAuth::Header => request.header("Authorization", format!("ApiKey {key}")),
+            KeyAuth::QueryParam => request.query(&[("key", key)]),
+        };
+        for (name, value) in &parts.headers {
+            request = request.header(name, value);
+        }
+        if let (Some(header), Some(id)) =
+            (&self.inner.config.request_id_header, &options.request_id)
+        {
+            request = request.header(header, id);
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(timeout) = options.timeout {
+            request = request.timeout(timeout);
+        }
+        let response = request.send().await?;
+        let status = response.status().as_u16();
+        let header_snapshot = options.meta_sink.as_ref().map(|_| {
+            response
+                .headers()
+                .iter()
+                .map(|(name, value)| {
+                    (
+                        name.as_str().to_owned(),
+                        value.to_str().unwrap_or_default().to_owned(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        });
+        let body = response.bytes().await?;
+        let elapsed = started.elapsed();
+        if let (Some(MetaSink(sink)), Some(headers)) = (&options.meta_sink, header_snapshot) {
+            *sink.lock().expect("meta sink poisoned") = Some(ResponseMeta {
+                url: parts.url.clone(),
+                status,
+                headers,
+                elapsed,
+                key: redact_key(key),
+                request_id: options.request_id.clone(),
+            });
+        }
+        for middleware in &self.inner.config.middleware.0 {
+            middleware.after_response(&crate::middleware::ResponseParts {
+                url: parts.url.clone(),
+                status,
+                elapsed,
+                body_len: body.len(),
+            });
+        }
+        if elapsed >= self.inner.config.slow_request_threshold {
+            self.inner.slow_requests.fetch_add(1, Ordering::Relaxed);
+            tracing::warn!(
+                url = %self.inner.config.log_redaction.render(url, query),
+                key = %self.key_display(key),
+                elapsed_ms = elapsed.as_millis() as u64,
+                request_id = options.request_id.as_deref().unwrap_or("-"),
+                "slow torn api request"
+            );
+        }
+
+        #[cfg(feature = "validate-responses")]
+        crate::validate::validate_body(url, &body);
+
+        decode_body(&body)
+    }
+}
+
+/// Decodes a response body, ruling out Torn's error envelope first: the API
+/// reports errors as a 200 whose body is exactly `{"error": {...}}`, and a
+/// raw-JSON target like `serde_json::Value` would otherwise absorb that
+/// shape as a success — bypassing key quarantine, penalization and health
+/// accounting. The envelope denies unknown fields, so on a success body this
+/// first attempt fails at the first key and the typed parse below stays the
+/// only full pass: straight from the raw bytes, no UTF-8 validation pass, no
+/// intermediate String.
+pub(crate) fn decode_body<T: DeserializeOwned>(body: &[u8]) -> Result<T> {
+    if let Ok(envelope) = serde_json::from_slice::<ApiErrorEnvelope>(body) {
+        return Err(TornError::Api(envelope.error));
+    }
+    serde_json::from_slice(body).map_err(TornError::from)
+}
+
+/// Handle returned by [`TornClient::static_data`]: lazy, once-per-process
+/// loaders for reference data that never changes within a session.
+///
+/// Backed by `OnceCell`s on the client, so concurrent callers share a single
+/// in-flight fetch and every later call is a cache hit.
+pub struct StaticData<'a> {
+    client: &'a TornClient,
+}
+
+impl<'a> StaticData<'a> {
+    /// The indexed item catalog; same cache as [`TornClient::item_catalog`].
+    pub async fn items(&self) -> Result<&'a crate::catalog::ItemCatalog> {
+        self.client.item_catalog().await
+    }
+
+    /// The crime list from `/torn/crimes`, fetched once and cached.
+    pub async fn crimes(&self) -> Result<&'a [crate::models::torn::TornCrime]> {
+        self.client
+            .note_cache_hit(self.client.inner.crimes.initialized());
+        let crimes = self
+            .client
+            .inner
+            .crimes
+            .get_or_try_init(|| async { self.client.torn().crimes().await })
+            .await?;
+        Ok(crimes)
+    }
+
+    /// The honor list from `/torn/honors`, fetched once and cached.
+    pub async fn honors(&self) -> Result<&'a [crate::models::torn::Honor]> {
+        self.client
+            .note_cache_hit(self.client.inner.honors.initialized());
+        let honors = self
+            .client
+            .inner
+            .honors
+            .get_or_try_init(|| async { self.client.torn().honors().await })
+            .await?;
+        Ok(honors)
+    }
+
+    /// The medal list from `/torn/medals`, fetched once and cached.
+    pub async fn medals(&self) -> Result<&'a [crate::models::torn::Medal]> {
+        self.client
+            .note_cache_hit(self.client.inner.medals.initialized());
+        let medals = self
+            .client
+            .inner
+            .medals
+            .get_or_try_init(|| async { self.client.torn().medals().await })
+            .await?;
+        Ok(medals)
+    }
+}
+
+/// Tracks one in-flight request; notifies shutdown waiters on completion.
+struct InFlightGuard<'a> {
+    inner: &'a ClientInner,
+}
+
+impl<'a> InFlightGuard<'a> {
+    fn enter(inner: &'a ClientInner) -> Self {
+        inner.in_flight.fetch_add(1, Ordering::SeqCst);
+        Self { inner }
+    }
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        if self.inner.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.inner.drain_notify.notify_waiters();
+        }
+    }
+}
+
+/// One pool key's answer in a [`KeyValidationReport`].
+#[derive(Debug)]
+pub struct KeyValidation {
+    /// The key, redacted the same way logs show it.
+    pub key: String,
+    /// Operator label, when one was attached via
+    /// [`TornClientConfig::api_key_labeled`].
+    pub label: Option<String>,
+    /// The key's `/key/info` answer — access level and selections — or the
+    /// error the check failed with.
+    pub info: Result<crate::models::key::KeyInfo>,
+}
+
+/// What [`TornClient::validate_keys`] found, one entry per pool key.
+#[derive(Debug)]
+pub struct KeyValidationReport {
+    /// Per-key results, in rotation order.
+    pub keys: Vec<KeyValidation>,
+}
+
+impl KeyValidationReport {
+    /// Whether every key answered `/key/info` successfully.
+    pub fn all_valid(&self) -> bool {
+        self.keys.iter().all(|entry| entry.info.is_ok())
+    }
+
+    /// The entries the server rejected as unusable keys (codes 2, 10
+    /// and 13). Transient failures — network errors, rate limits — are not
+    /// listed here; they say nothing about the credential itself.
+    pub fn invalid(&self) -> Vec<&KeyValidation> {
+        self.keys
+            .iter()
+            .filter(|entry| {
+                entry.info.as_ref().is_err_and(|error| {
+                    error
+                        .api_code()
+                        .is_some_and(crate::error::codes::is_key_invalid)
+                })
+            })
+            .collect()
+    }
+}
+
+/// The cached answer of `/key/info`: the key's access level and which
+/// selections it can request per section.
+#[derive(Debug, Clone)]
+pub struct KeyCapabilities {
+    access_level: Option<AccessLevel>,
+    selections: HashMap<String, Vec<String>>,
+}
+
+impl KeyCapabilities {
+    pub(crate) fn from_info(info: crate::models::key::KeyInfo) -> Self {
+        Self {
+            access_level: AccessLevel::try_from(info.access.level).ok(),
+            selections: info.selections,
+        }
+    }
+
+    /// The key's access level, if the server reported a known one.
+    pub fn access_level(&self) -> Option<AccessLevel> {
+        self.access_level
+    }
+
+    /// Whether `selection` in `section` is available to this key.
+    pub fn can_access(&self, section: &str, selection: &str) -> bool {
+        self.selections
+            .get(section)
+            .is_some_and(|selections| selections.iter().any(|s| s == selection))
+    }
+}
+
+/// Longest `comment` value the API accepts without truncating.
+pub const MAX_COMMENT_LENGTH: usize = 15;
+
+/// Checks a `comment` value against Torn's length and character rules.
+pub(crate) fn validate_comment(comment: &str) -> Result<()> {
+    if comment.is_empty() {
+        return Err(TornError::InvalidComment("comment is empty".to_owned()));
+    }
+    if comment.chars().count() > MAX_COMMENT_LENGTH {
+        return Err(TornError::InvalidComment(format!(
+            "comment is longer than {MAX_COMMENT_LENGTH} characters"
+        )));
+    }
+    if let Some(bad) = comment
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, ' ' | '_' | '-' | '.')))
+    {
+        return Err(TornError::InvalidComment(format!(
+            "character {bad:?} is not allowed"
+        )));
+    }
+    Ok(())
+}
+
+/// Parses a key file: one key per line, blank lines and `#` comments ignored.
+fn parse_key_file(contents: &str) -> Vec<String> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Parses a boolean environment flag, naming the variable on failure.
+fn parse_env_bool(name: &str, value: &str) -> Result<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Ok(true),
+        "0" | "false" | "no" | "off" => Ok(false),
+        other => Err(TornError::InvalidParams(format!(
+            "{name}: expected a boolean, got {other:?}"
+        ))),
+    }
+}
+
+/// Local wall-clock unix time in seconds.
+pub(crate) fn local_unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// A process-unique correlation ID — random per-process prefix plus a
+/// counter — unique without a uuid dependency; see
+/// [`TornClientConfig::request_id_header`].
+fn next_request_id() -> String {
+    use std::hash::{BuildHasher, Hasher};
+    static PREFIX: std::sync::OnceLock<u32> = std::sync::OnceLock::new();
+    static SEQ: AtomicU64 = AtomicU64::new(0);
+    let prefix = PREFIX.get_or_init(|| {
+        std::collections::hash_map::RandomState::new()
+            .build_hasher()
+            .finish() as u32
+    });
+    format!("{prefix:08x}-{:x}", SEQ.fetch_add(1, Ordering::Relaxed))
+}
+
+/// Shortens an API key for log output so full credentials never hit logs.
+pub(crate) fn redact_key(key: &str) -> String {
+    if key.len() <= 4 {
+        "***".to_owned()
+    } else {
+        format!("{}***", &key[..4])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_params_merge_without_clobbering_explicit_ones() {
+        let client = TornClient::new(
+            TornClientConfig::new("k")
+                .default_param("striptags", "true")
+                .endpoint_default_param("user", "limit", "100"),
+        );
+        let merged = client.apply_default_params("/user/attacks", &[("limit", "25".to_owned())]);
+        assert!(merged.contains(&("limit".to_owned(), "25".to_owned())));
+        assert!(merged.contains(&("striptags".to_owned(), "true".to_owned())));
+        assert!(!merged.contains(&("limit".to_owned(), "100".to_owned())));
+
+        // Section defaults only apply to their own section.
+        let merged = client.apply_default_params("/faction/news", &[]);
+        assert!(merged.contains(&("striptags".to_owned(), "true".to_owned())));
+        assert!(!merged.iter().any(|(name, _)| name == "limit"));
+    }
+
+    #[tokio::test]
+    async fn paused_client_fails_fast_in_error_mode() {
+        let client = TornClient::new(
+            TornClientConfig::new("k")
+                .base_url("http://127.0.0.1:0")
+                .pause_mode(PauseMode::Error),
+        );
+        client.pause();
+        assert!(client.is_paused());
+        let err = client.user().profile().await.unwrap_err();
+        assert!(matches!(err, TornError::Paused));
+
+        // After resume the request gets past the pause gate (and then fails
+        // on the unroutable address instead).
+        client.resume();
+        let err = client.user().profile().await.unwrap_err();
+        assert!(!matches!(err, TornError::Paused));
+    }
+
+    #[tokio::test]
+    async fn ip_block_cooloff_fails_fast_in_error_mode() {
+        let client = TornClient::new(
+            TornClientConfig::new("k")
+                .base_url("http://127.0.0.1:0")
+                .pause_mode(PauseMode::Error)
+                .ip_block_cooloff(Duration::from_secs(60)),
+        );
+        assert!(client.cooloff_remaining().is_none());
+        client.begin_ip_block_cooloff();
+        assert!(client.cooloff_remaining().is_some());
+        let err = client.user().profile().await.unwrap_err();
+        assert!(matches!(err, TornError::CoolingOff { .. }));
+    }
+
+    #[test]
+    fn raw_json_targets_still_surface_v1_error_envelopes() {
+        let body = br#"{"error":{"code":2,"error":"Incorrect key"}}"#;
+        let err = decode_body::<serde_json::Value>(body).unwrap_err();
+        assert_eq!(err.api_code(), Some(crate::error::codes::INCORRECT_KEY));
+
+        // A success body with an `error`-named field below the top level is
+        // not the envelope and decodes normally.
+        let body = br#"{"profile":{"error":false},"name":"x"}"#;
+        let value = decode_body::<serde_json::Value>(body).unwrap();
+        assert_eq!(value["name"], "x");
+    }
+
+    #[test]
+    fn get_raw_bodies_classify_error_envelopes_for_the_retry_path() {
+        // `get_raw` decodes into `Value`; an envelope must still come back
+        // as `TornError::Api` so penalization and retry logic can see it.
+        let body = br#"{"error":{"code":5,"error":"Too many requests"}}"#;
+        let err = decode_body::<serde_json::Value>(body).unwrap_err();
+        assert!(matches!(&err, TornError::Api(body) if body.code == 5));
+        assert!(err.is_transient());
+    }
+
+    #[tokio::test]
+    async fn request_ids_wrap_failures_and_honor_caller_overrides() {
+        let client = TornClient::new(
+            TornClientConfig::new("k")
+                .base_url("http://127.0.0.1:0")
+                .request_id_header("x-request-id"),
+        );
+        let err = client.user().profile().await.unwrap_err();
+        assert!(err.request_id().is_some());
+        // The underlying classification stays reachable through the wrapper.
+        assert!(err.is_transient() || err.api_code().is_none());
+
+        let options = RequestOptions::new().request_id("upstream-42");
+        let err = client
+            .get_with_options::<serde_json::Value>("/user/profile", &[], &options)
+            .await
+            .unwrap_err();
+        assert_eq!(err.request_id(), Some("upstream-42"));
+    }
+
+    #[tokio::test]
+    async fn middleware_runs_before_send_but_not_after_transport_failure() {
+        struct Recorder(Arc<std::sync::Mutex<(u64, u64)>>);
+        impl crate::middleware::Middleware for Recorder {
+            fn before_request(&self, parts: &mut crate::middleware::RequestParts) {
+                self.0.lock().unwrap().0 += 1;
+                parts.headers.push(("x-audit".to_owned(), "1".to_owned()));
+            }
+            fn after_response(&self, _parts: &crate::middleware::ResponseParts) {
+                self.0.lock().unwrap().1 += 1;
+            }
+        }
+        let calls = Arc::new(std::sync::Mutex::new((0, 0)));
+        let client = TornClient::new(
+            TornClientConfig::new("k")
+                .base_url("http://127.0.0.1:0")
+                .middleware(Recorder(calls.clone())),
+        );
+        let _ = client.user().profile().await;
+        // The request never reached a server, so only the before side ran.
+        assert_eq!(*calls.lock().unwrap(), (1, 0));
+    }
+
+    #[tokio::test]
+    async fn fetch_page_rejects_a_malformed_stored_link() {
+        let client = TornClient::new(TornClientConfig::new("k"));
+        let err = client.fetch_page::<u32>("not a url").await.unwrap_err();
+        assert!(matches!(err, TornError::InvalidParams(_)));
+    }
+
+    #[tokio::test]
+    async fn shutdown_rejects_new_requests_and_reports_drain() {
+        let client = TornClient::new(TornClientConfig::new("k").base_url("http://127.0.0.1:0"));
+        assert!(!client.is_shut_down());
+        let report = client.shutdown(Duration::from_millis(50)).await;
+        assert!(client.is_shut_down());
+        assert!(report.drained);
+        assert_eq!(report.aborted_in_flight, 0);
+        let err = client.user().profile().await.unwrap_err();
+        assert!(matches!(err, TornError::ShutDown));
+    }
+
+    #[test]
+    fn request_options_accumulate_tweaks() {
+        let options = RequestOptions::new()
+            .param("cat", "attack")
+            .header("X-Trace", "abc")
+            .timeout(Duration::from_secs(3))
+            .bypass_cache();
+        assert_eq!(
+            options.params,
+            vec![("cat".to_owned(), "attack".to_owned())]
+        );
+        assert_eq!(
+            options.headers,
+            vec![("X-Trace".to_owned(), "abc".to_owned())]
+        );
+        assert_eq!(options.timeout, Some(Duration::from_secs(3)));
+        assert!(options.bypass_cache);
+
+        let options = options.rate_limit_mode(RateLimitMode::Error);
+        assert_eq!(options.rate_limit_mode, Some(RateLimitMode::Error));
+    }
+
+    #[tokio::test]
+    async fn cancelled_token_aborts_before_anything_is_sent() {
+        let token = tokio_util::sync::CancellationToken::new();
+        token.cancel();
+
+        // Per-request token.
+        let client = TornClient::new(TornClientConfig::new("k"));
+        let options = RequestOptions::new().cancellation_token(token.clone());
+        let err = client
+            .get_with_options::<serde_json::Value>("user/basic", &[], &options)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TornError::Cancelled));
+
+        // Client-wide token.
+        let client = TornClient::new(TornClientConfig::new("k").cancellation_token(token));
+        let err = client.user().profile().await.unwrap_err();
+        assert!(matches!(err, TornError::Cancelled));
+    }
+
+    #[tokio::test]
+    async fn least_used_balancing_favors_the_idle_key() {
+        let config =
+            TornClientConfig::with_keys(["busy", "idle"]).key_balancing(ApiKeyBalancing::LeastUsed);
+        let client = TornClient::new(config);
+        for _ in 0..3 {
+            assert!(
+                client
+                    .inner
+                    .limiter
+                    .acquire("busy", RateLimitMode::Error)
+                    .await
+            );
+        }
+        assert_eq!(client.pick_pool_key().await.as_deref(), Some("idle"));
+    }
+
+    #[tokio::test]
+    async fn least_loaded_balancing_steers_around_cold_keys() {
+        let config = TornClientConfig::with_keys(["cold", "warm"])
+            .key_balancing(ApiKeyBalancing::LeastLoaded);
+        let client = TornClient::new(config);
+        assert!(
+            client
+                .inner
+                .limiter
+                .acquire("warm", RateLimitMode::Error)
+                .await
+        );
+        client.inner.limiter.penalize("cold");
+        assert_eq!(client.pick_pool_key().await.as_deref(), Some("warm"));
+    }
+
+    #[tokio::test]
+    async fn key_labels_surface_in_status_and_log_display() {
+        let config = TornClientConfig::with_keys(["anon"]).api_key_labeled("donated", "alice");
+        let client = TornClient::new(config);
+        assert!(
+            client
+                .inner
+                .limiter
+                .acquire("donated", RateLimitMode::Error)
+                .await
+        );
+        assert!(
+            client
+                .inner
+                .limiter
+                .acquire("anon", RateLimitMode::Error)
+                .await
+        );
+
+        let status = client.rate_limit_status().await;
+        assert_eq!(status["donated"].label.as_deref(), Some("alice"));
+        assert_eq!(status["anon"].label, None);
+
+        assert_eq!(
+            client.key_display("donated"),
+            format!("alice ({})", redact_key("donated"))
+        );
+        assert_eq!(client.key_display("anon"), redact_key("anon"));
+    }
+
+    #[test]
+    fn validation_report_separates_invalid_keys_from_transient_failures() {
+        let entry = |info| KeyValidation {
+            key: redact_key("k"),
+            label: None,
+            info,
+        };
+        let api_error = |code| {
+            TornError::Api(crate::error::ApiErrorBody {
+                code,
+                message: String::new(),
+            })
+        };
+        let info: crate::models::key::KeyInfo =
+            serde_json::from_str(r#"{"access": {"level": 3, "type": "Limited"}}"#).unwrap();
+
+        let report = KeyValidationReport {
+            keys: vec![
+                entry(Ok(info)),
+                entry(Err(api_error(crate::error::codes::INCORRECT_KEY))),
+                entry(Err(api_error(crate::error::codes::TOO_MANY_REQUESTS))),
+            ],
+        };
+        assert!(!report.all_valid());
+        // Only the incorrect key counts as invalid; the rate-limited check
+        // says nothing about its credential.
+        assert_eq!(report.invalid().len(), 1);
+    }
+
+    #[test]
+    fn sessions_stick_to_one_pool_key_per_name() {
+        let client = TornClient::new(TornClientConfig::with_keys(["k1", "k2", "k3"]));
+        let first = client.session("war-targets").key_override.unwrap();
+        let second = client.session("war-targets").key_override.unwrap();
+        assert_eq!(first, second);
+        assert!(client.inner.keys.keys().contains(&first));
+
+        let empty = TornClient::new(TornClientConfig::with_keys(Vec::<String>::new()));
+        assert!(empty.session("war-targets").key_override.is_none());
+    }
+
+    #[test]
+    fn client_and_config_debug_never_show_raw_keys() {
+        let config = TornClientConfig::with_keys(["supersecretkey123"])
+            .api_key_labeled("donatedkey456", "alice");
+        assert!(!format!("{config:?}").contains("secretkey"));
+        assert!(!format!("{config:?}").contains("donatedkey"));
+
+        let client = TornClient::new(config).with_key("pinnedkey789");
+        let rendered = format!("{client:?}");
+        assert!(!rendered.contains("secretkey"), "{rendered}");
+        assert!(!rendered.contains("pinnedkey"), "{rendered}");
+    }
+
+    #[test]
+    fn pinned_key_handles_share_state_and_compose_with_mode_overrides() {
+        let client = TornClient::new(TornClientConfig::with_keys(["k1", "k2"]));
+        let pinned = client.with_key("k2");
+        assert_eq!(pinned.key_override.as_deref(), Some("k2"));
+        assert!(Arc::ptr_eq(&client.inner, &pinned.inner));
+
+        let pinned_fast = pinned.with_rate_limit_mode(RateLimitMode::Error);
+        assert_eq!(pinned_fast.key_override.as_deref(), Some("k2"));
+        assert_eq!(pinned_fast.rate_limit_mode(), RateLimitMode::Error);
+        assert!(client.key_override.is_none());
+    }
+
+    #[test]
+    fn v1_base_url_strips_the_v2_suffix() {
+        let client = TornClient::new(TornClientConfig::new("k"));
+        assert_eq!(client.v1_base_url(), "https://api.torn.com");
+
+        let mocked =
+            TornClient::new(TornClientConfig::new("k").base_url("http://127.0.0.1:9999/v2"));
+        assert_eq!(mocked.v1_base_url(), "http://127.0.0.1:9999");
+    }
+
+    #[test]
+    fn from_env_reads_pool_mode_and_comment() {
+        let env = |name: &str| match name {
+            "TORN_API_KEYS" => Some("aaa, bbb,,ccc".to_owned()),
+            "TORN_RATE_LIMIT_MODE" => Some("error".to_owned()),
+            "TORN_COMMENT" => Some("my-service".to_owned()),
+            "TORN_PREFLIGHT_ACCESS_CHECK" => Some("yes".to_owned()),
+            _ => None,
+        };
+        let config = TornClientConfig::from_env_with(env).unwrap();
+        assert_eq!(*config.keys, vec!["aaa", "bbb", "ccc"]);
+        assert_eq!(config.rate_limit_mode, RateLimitMode::Error);
+        assert_eq!(config.comment.as_deref(), Some("my-service"));
+        assert!(config.preflight_access_check);
+    }
+
+    #[test]
+    fn from_env_reports_malformed_values() {
+        let missing = TornClientConfig::from_env_with(|_| None);
+        assert!(matches!(missing, Err(TornError::InvalidParams(_))));
+
+        let bad_mode = TornClientConfig::from_env_with(|name| {
+            (name == "TORN_API_KEY")
+                .then(|| "k".to_owned())
+                .or_else(|| (name == "TORN_RATE_LIMIT_MODE").then(|| "sometimes".to_owned()))
+        });
+        match bad_mode {
+            Err(TornError::InvalidParams(msg)) => assert!(msg.contains("TORN_RATE_LIMIT_MODE")),
+            other => panic!("expected InvalidParams, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn app_user_agent_keeps_the_library_identifiable() {
+        let config = TornClientConfig::new("k").app_user_agent("my-war-bot/2.1");
+        let ua = config.user_agent.as_deref().unwrap();
+        assert!(ua.starts_with("my-war-bot/2.1 "));
+        assert!(ua.ends_with(DEFAULT_USER_AGENT));
+
+        let config = TornClientConfig::new("k").user_agent("curl/8.0");
+        assert_eq!(config.user_agent.as_deref(), Some("curl/8.0"));
+    }
+
+    #[test]
+    fn proxy_urls_are_validated_at_build_time() {
+        let config = TornClientConfig::new("k")
+            .proxy("http://proxy.internal:3128")
+            .unwrap()
+            .proxy_auth("collector", "hunter2");
+        // The proxied transport still builds and assembles request URLs.
+        let client = TornClient::new(config);
+        let merged = client.apply_default_params("/user/attacks", &[]);
+        assert!(merged.is_empty());
+
+        assert!(TornClientConfig::new("k").proxy("not a proxy url").is_err());
+    }
+
+    #[test]
+    fn rate_limit_mode_override_is_per_handle() {
+        let client =
+            TornClient::new(TornClientConfig::new("k").rate_limit_mode(RateLimitMode::AutoDelay));
+        let interactive = client.with_rate_limit_mode(RateLimitMode::Error);
+        assert_eq!(interactive.rate_limit_mode(), RateLimitMode::Error);
+        // The original handle (and its endpoint clones) keep the default.
+        assert_eq!(client.rate_limit_mode(), RateLimitMode::AutoDelay);
+        // Both handles share the same underlying client state.
+        assert!(Arc::ptr_eq(&client.inner, &interactive.inner));
+    }
+
+    #[test]
+    fn log_redaction_scrubs_what_the_policy_names() {
+        let query = vec![
+            ("limit".to_owned(), "100".to_owned()),
+            ("comment".to_owned(), "my-bot".to_owned()),
+        ];
+        let url = "https://api.torn.com/v2/user/2503189/profile";
+
+        let shown = LogRedaction::new().render(url, &query);
+        assert_eq!(
+            shown,
+            "https://api.torn.com/v2/user/2503189/profile?limit=100&comment=my-bot"
+        );
+
+        let commentless = LogRedaction::new().redact_comment().render(url, &query);
+        assert!(commentless.contains("limit=100"));
+        assert!(commentless.contains("comment=<redacted>"));
+        assert!(!commentless.contains("my-bot"));
+
+        let paramless = LogRedaction::new().redact_params().render(url, &query);
+        assert!(paramless.ends_with("?<redacted>"));
+
+        let hashed = LogRedaction::new().hash_ids().render(url, &[]);
+        assert!(!hashed.contains("2503189"));
+        assert!(hashed.contains("/user/id#"));
+        // Same ID hashes the same way, so log lines still correlate.
+        assert_eq!(hashed, LogRedaction::new().hash_ids().render(url, &[]));
+    }
+
+    #[test]
+    fn torn_now_applies_recorded_skew() {
+        let client = TornClient::new(TornClientConfig::new("k"));
+        assert!(client.clock_skew().is_none());
+        let skew = client.record_server_time(local_unix_now() + 7);
+        assert_eq!(skew, 7);
+        assert_eq!(client.clock_skew(), Some(7));
+        let drift = client.torn_now() - local_unix_now();
+        assert!((6..=8).contains(&drift));
+    }
+
+    #[test]
+    fn key_files_skip_blanks_and_comments() {
+        let parsed = parse_key_file("# faction keys\nabc\n\n  def  \n#ghi\n");
+        assert_eq!(parsed, vec!["abc".to_owned(), "def".to_owned()]);
+    }
+
+    #[test]
+    fn comments_are_validated_at_build_time() {
+        assert!(TornClientConfig::new("k").comment("my-bot v1.2").is_ok());
+        assert!(TornClientConfig::new("k").comment("").is_err());
+        assert!(TornClientConfig::new("k")
+            .comment("sixteen chars !!")
+            .is_err());
+        assert!(TornClientConfig::new("k").comment("naughty&chars").is_err());
+
+        let config = TornClientConfig::new("k").comment("mybot").unwrap();
+        let client = TornClient::new(config);
+        let merged = client.apply_default_params("/user/attacks", &[]);
+        assert!(merged.contains(&("comment".to_owned(), "mybot".to_owned())));
+    }
+
+    #[test]
+    fn capabilities_answer_selection_queries() {
+        let info: crate::models::key::KeyInfo = serde_json::from_str(
+            r#"{"access":{"level":3,"type":"Limited Access"},
+                "selections":{"user":["basic","attacks"],"faction":["basic"]}}"#,
+        )
+        .unwrap();
+        let caps = KeyCapabilities::from_info(info);
+        assert_eq!(caps.access_level(), Some(AccessLevel::Limited));
+        assert!(caps.can_access("user", "attacks"));
+        assert!(!caps.can_access("faction", "attacks"));
+        assert!(caps.access_level() >= Some(AccessLevel::Minimal));
+    }
+
+    #[test]
+    fn preflight_guard_blocks_unavailable_selections() {
+        let client = TornClient::new(TornClientConfig::new("k").preflight_access_check(true));
+        // Unarmed (capabilities not fetched): everything passes.
+        assert!(client.check_preflight_access("/faction/attacks").is_ok());
+
+        let info: crate::models::key::KeyInfo = serde_json::from_str(
+            r#"{"access":{"level":2,"type":"Minimal Access"},
+                "selections":{"user":["profile"]}}"#,
+        )
+        .unwrap();
+        client
+            .inner
+            .capabilities
+            .set(KeyCapabilities::from_info(info))
+            .unwrap();
+        assert!(client.check_preflight_access("/user/profile").is_ok());
+        let err = client
+            .check_preflight_access("/faction/attacks")
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            TornError::InsufficientAccess {
+                actual: Some(AccessLevel::Minimal),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn redacted_keys_never_contain_the_full_secret() {
+        assert_eq!(redact_key("abc"), "***");
+        assert_eq!(redact_key("abcdefgh"), "abcd***");
+    }
+}
@@ -0,0 +1,27 @@
+//! Target shims for `wasm32-unknown-unknown` (the `wasm` feature).
+//!
+//! The client's async sleeps go through [`sleep`] so they can be backed by
+//! tokio timers on native targets and by `gloo-timers` (the browser event
+//! loop) on wasm. Everything else the core client needs — reqwest's fetch
+//! backend, the `tokio::sync` primitives — already compiles for wasm;
+//! tokio's runtime and timer features do not, which is why the wasm build
+//! gates them out in `Cargo.toml`.
+
+use std::time::Duration;
+
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm")))]
+compile_error!(
+    "building for wasm32 requires the `wasm` feature, which provides gloo-timers sleeps"
+);
+
+/// Sleeps for `duration` on whatever timer the target provides.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+/// Sleeps for `duration` on whatever timer the target provides.
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn sleep(duration: Duration) {
+    gloo_timers::future::sleep(duration).await;
+}
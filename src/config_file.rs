@@ -0,0 +1,139 @@
+//! File-based configuration (the `config` feature).
+//!
+//! Deployments keep API key pools and tuning out of code by pointing
+//! [`TornClientConfig::from_file`] at a TOML or JSON file:
+//!
+//! ```toml
+//! keys = ["KEY_ONE", "KEY_TWO"]
+//! rate_limit_mode = "auto-delay"
+//! comment = "my-service"
+//! user_agent = "my-war-bot/2.1"
+//! ```
+//!
+//! The format is chosen by file extension (`.toml` or `.json`). Unknown
+//! fields are rejected so typos fail loudly instead of being silently
+//! ignored.
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::client::TornClientConfig;
+use crate::error::TornError;
+use crate::Result;
+
+/// Serde-facing shape of a configuration file. Kept separate from
+/// [`TornClientConfig`] because the live config holds non-serializable
+/// state (trait objects, a pre-built HTTP client).
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FileConfig {
+    keys: Vec<String>,
+    base_url: Option<String>,
+    /// `auto-delay`, `error` or `off`.
+    rate_limit_mode: Option<String>,
+    comment: Option<String>,
+    user_agent: Option<String>,
+    timeout_secs: Option<u64>,
+    connect_timeout_secs: Option<u64>,
+    proxy: Option<String>,
+}
+
+impl FileConfig {
+    fn into_config(self) -> Result<TornClientConfig> {
+        if self.keys.is_empty() {
+            return Err(TornError::InvalidParams(
+                "config file lists no API keys".to_owned(),
+            ));
+        }
+        let mut config = TornClientConfig::with_keys(self.keys);
+        if let Some(base_url) = self.base_url {
+            config = config.base_url(base_url);
+        }
+        if let Some(mode) = self.rate_limit_mode {
+            config = config.rate_limit_mode(mode.parse()?);
+        }
+        if let Some(comment) = self.comment {
+            config = config.comment(comment)?;
+        }
+        if let Some(user_agent) = self.user_agent {
+            config = config.user_agent(user_agent);
+        }
+        if let Some(secs) = self.timeout_secs {
+            config = config.timeout(std::time::Duration::from_secs(secs));
+        }
+        if let Some(secs) = self.connect_timeout_secs {
+            config = config.connect_timeout(std::time::Duration::from_secs(secs));
+        }
+        if let Some(proxy) = self.proxy {
+            config = config.proxy(proxy)?;
+        }
+        Ok(config)
+    }
+}
+
+impl TornClientConfig {
+    /// Loads configuration from a TOML (`.toml`) or JSON (`.json`) file.
+    ///
+    /// Recognized fields: `keys` (required, non-empty), `base_url`,
+    /// `rate_limit_mode`, `comment`, `user_agent`, `timeout_secs`,
+    /// `connect_timeout_secs` and `proxy`. Unknown fields and malformed
+    /// values fail with [`TornError::InvalidParams`].
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            TornError::InvalidParams(format!("failed to read {}: {e}", path.display()))
+        })?;
+        let extension = path.extension().and_then(|ext| ext.to_str());
+        let file: FileConfig = match extension {
+            Some("toml") => toml::from_str(&contents)
+                .map_err(|e| TornError::InvalidParams(format!("{}: {e}", path.display())))?,
+            Some("json") => serde_json::from_str(&contents)
+                .map_err(|e| TornError::InvalidParams(format!("{}: {e}", path.display())))?,
+            _ => {
+                return Err(TornError::InvalidParams(format!(
+                    "{}: unsupported config format (expected .toml or .json)",
+                    path.display()
+                )))
+            }
+        };
+        file.into_config()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rate_limit::RateLimitMode;
+
+    #[test]
+    fn toml_config_maps_onto_the_builder() {
+        let file: FileConfig = toml::from_str(
+            r#"
+            keys = ["aaa", "bbb"]
+            rate_limit_mode = "error"
+            comment = "my-service"
+            timeout_secs = 10
+            "#,
+        )
+        .unwrap();
+        let config = file.into_config().unwrap();
+        assert_eq!(*config.keys, vec!["aaa", "bbb"]);
+        assert_eq!(config.rate_limit_mode, RateLimitMode::Error);
+        assert_eq!(config.comment.as_deref(), Some("my-service"));
+        assert_eq!(config.timeout, Some(std::time::Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn unknown_fields_and_empty_pools_are_rejected() {
+        let typo: std::result::Result<FileConfig, _> =
+            toml::from_str("keys = [\"a\"]\nrate_limit_mdoe = \"off\"");
+        assert!(typo.is_err());
+
+        let empty: FileConfig = serde_json::from_str(r#"{"keys": []}"#).unwrap();
+        assert!(matches!(
+            empty.into_config(),
+            Err(TornError::InvalidParams(_))
+        ));
+    }
+}
@@ -0,0 +1,272 @@
+//! Opt-in domain layer over the generated models.
+//!
+//! The wire models mirror the OpenAPI spec and are Option-heavy; code that
+//! has already validated a response ends up unwrapping the same fields over
+//! and over. This module offers ergonomic structs — required fields are
+//! non-optional, free-text statuses become enums — with [`TryFrom`]
+//! conversions that say exactly which field was missing or malformed.
+
+use std::str::FromStr;
+
+use crate::models::{faction, user};
+
+/// Why a wire model could not be lifted into its domain type.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DomainError {
+    /// A field the domain type requires was absent in the response.
+    #[error("missing field `{0}`")]
+    MissingField(&'static str),
+    /// A status string did not match any known variant.
+    #[error("unknown value {value:?} for `{field}`")]
+    UnknownVariant { field: &'static str, value: String },
+}
+
+/// Online state from a `last_action` block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OnlineStatus {
+    Online,
+    Idle,
+    Offline,
+}
+
+impl FromStr for OnlineStatus {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Online" => Ok(OnlineStatus::Online),
+            "Idle" => Ok(OnlineStatus::Idle),
+            "Offline" => Ok(OnlineStatus::Offline),
+            other => Err(DomainError::UnknownVariant {
+                field: "last_action.status",
+                value: other.to_owned(),
+            }),
+        }
+    }
+}
+
+/// Player state from a `status` block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlayerState {
+    Okay,
+    Hospital,
+    Jail,
+    Traveling,
+    Abroad,
+    Federal,
+    Fallen,
+}
+
+impl FromStr for PlayerState {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Okay" => Ok(PlayerState::Okay),
+            "Hospital" => Ok(PlayerState::Hospital),
+            "Jail" => Ok(PlayerState::Jail),
+            "Traveling" => Ok(PlayerState::Traveling),
+            "Abroad" => Ok(PlayerState::Abroad),
+            "Federal" => Ok(PlayerState::Federal),
+            "Fallen" => Ok(PlayerState::Fallen),
+            other => Err(DomainError::UnknownVariant {
+                field: "status.state",
+                value: other.to_owned(),
+            }),
+        }
+    }
+}
+
+/// A fully identified attack participant.
+///
+/// The wire type leaves every field optional because stealthed attackers are
+/// anonymised; here, an anonymous participant is represented by the *absence*
+/// of the whole struct instead.
+#[derive(Debug, Clone)]
+pub struct Participant {
+    pub id: u64,
+    pub name: String,
+    pub faction: Option<user::AttackFaction>,
+}
+
+impl TryFrom<user::AttackParticipant> for Participant {
+    type Error = DomainError;
+
+    fn try_from(wire: user::AttackParticipant) -> Result<Self, Self::Error> {
+        Ok(Participant {
+            id: wire.id.ok_or(DomainError::MissingField("participant.id"))?,
+            name: wire
+                .name
+                .ok_or(DomainError::MissingField("participant.name"))?,
+            faction: wire.faction,
+        })
+    }
+}
+
+/// An attack with identified participants where the API provides them.
+#[derive(Debug, Clone)]
+pub struct Attack {
+    pub id: u64,
+    pub code: String,
+    pub started: i64,
+    pub ended: i64,
+    /// `None` for stealthed/anonymous attackers.
+    pub attacker: Option<Participant>,
+    pub defender: Participant,
+    pub result: String,
+    pub respect_gain: f64,
+    pub respect_loss: f64,
+    pub chain: u32,
+    pub is_stealthed: bool,
+}
+
+impl TryFrom<user::Attack> for Attack {
+    type Error = DomainError;
+
+    fn try_from(wire: user::Attack) -> Result<Self, Self::Error> {
+        // A stealthed attacker arrives as an all-null participant object;
+        // treat it the same as an absent one.
+        let attacker = match wire.attacker {
+            Some(p) if p.id.is_some() => Some(Participant::try_from(p)?),
+            _ => None,
+        };
+        Ok(Attack {
+            id: wire.id,
+            code: wire.code,
+            started: wire.started,
+            ended: wire.ended,
+            attacker,
+            defender: Participant::try_from(wire.defender)?,
+            result: wire.result,
+            respect_gain: wire.respect_gain,
+            respect_loss: wire.respect_loss,
+            chain: wire.chain,
+            is_stealthed: wire.is_stealthed,
+        })
+    }
+}
+
+/// A user profile with parsed status enums.
+#[derive(Debug, Clone)]
+pub struct UserProfile {
+    pub player_id: u64,
+    pub name: String,
+    pub level: u32,
+    pub online: OnlineStatus,
+    pub last_action: i64,
+    pub state: PlayerState,
+    pub state_until: Option<i64>,
+}
+
+impl TryFrom<user::UserProfile> for UserProfile {
+    type Error = DomainError;
+
+    fn try_from(wire: user::UserProfile) -> Result<Self, Self::Error> {
+        Ok(UserProfile {
+            player_id: wire.player_id,
+            name: wire.name,
+            level: wire.level,
+            online: wire.last_action.status.parse()?,
+            last_action: wire.last_action.timestamp,
+            state: wire.status.state.parse()?,
+            state_until: wire.status.until,
+        })
+    }
+}
+
+/// A faction member with parsed status enums.
+#[derive(Debug, Clone)]
+pub struct FactionMember {
+    pub id: u64,
+    pub name: String,
+    pub level: u32,
+    pub position: String,
+    pub days_in_faction: u32,
+    pub is_revivable: bool,
+    pub online: OnlineStatus,
+    pub last_action: i64,
+    pub state: PlayerState,
+    pub state_until: Option<i64>,
+}
+
+impl TryFrom<faction::FactionMember> for FactionMember {
+    type Error = DomainError;
+
+    fn try_from(wire: faction::FactionMember) -> Result<Self, Self::Error> {
+        Ok(FactionMember {
+            id: wire.id,
+            name: wire.name,
+            level: wire.level,
+            position: wire.position,
+            days_in_faction: wire.days_in_faction,
+            is_revivable: wire.is_revivable,
+            online: wire.last_action.status.parse()?,
+            last_action: wire.last_action.timestamp,
+            state: wire.status.state.parse()?,
+            state_until: wire.status.until,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wire_participant(id: Option<u64>) -> user::AttackParticipant {
+        user::AttackParticipant {
+            id,
+            name: id.map(|_| "Someone".to_owned()),
+            level: id.map(|_| 10),
+            faction: None,
+        }
+    }
+
+    fn wire_attack(attacker: Option<user::AttackParticipant>) -> user::Attack {
+        user::Attack {
+            id: 1,
+            code: "abc".into(),
+            started: 100,
+            ended: 160,
+            attacker,
+            defender: wire_participant(Some(2)),
+            result: "Hospitalized".into(),
+            respect_gain: 4.5,
+            respect_loss: 0.0,
+            chain: 10,
+            is_stealthed: false,
+            is_raid: false,
+            is_ranked_war: false,
+            modifiers: None,
+        }
+    }
+
+    #[test]
+    fn identified_attacker_is_lifted() {
+        let attack = Attack::try_from(wire_attack(Some(wire_participant(Some(5))))).unwrap();
+        let attacker = attack.attacker.unwrap();
+        assert_eq!(attacker.id, 5);
+        assert_eq!(attacker.name, "Someone");
+    }
+
+    #[test]
+    fn anonymised_attacker_becomes_none() {
+        let attack = Attack::try_from(wire_attack(Some(wire_participant(None)))).unwrap();
+        assert!(attack.attacker.is_none());
+    }
+
+    #[test]
+    fn unknown_status_is_a_descriptive_error() {
+        assert_eq!(
+            "Sleeping".parse::<OnlineStatus>().unwrap_err(),
+            DomainError::UnknownVariant {
+                field: "last_action.status",
+                value: "Sleeping".into(),
+            }
+        );
+        assert_eq!("Idle".parse::<OnlineStatus>().unwrap(), OnlineStatus::Idle);
+        assert_eq!(
+            "Hospital".parse::<PlayerState>().unwrap(),
+            PlayerState::Hospital
+        );
+    }
+}
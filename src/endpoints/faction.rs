@@ -0,0 +1,250 @@
+//! Handle for the `/faction` section.
+
+use std::collections::HashMap;
+
+use futures_util::StreamExt;
+use serde::Deserialize;
+
+use crate::client::{RequestOptions, TornClient};
+use crate::ids::FactionId;
+use crate::models::faction::{
+    FactionBasic, FactionHof, FactionMember, FactionNews, FactionPosition,
+};
+use crate::models::torn::{RacketTerritory, Territory};
+use crate::models::user::{Attack, Revive};
+use crate::pagination::{ItemStream, PaginatedResponse};
+use crate::Result;
+
+use super::get_paged;
+use super::torn::TornEndpoint;
+
+#[derive(Deserialize)]
+struct BasicResponse {
+    basic: FactionBasic,
+}
+
+#[derive(Deserialize)]
+struct MembersResponse {
+    members: Vec<FactionMember>,
+}
+
+/// Handle for `/faction` routes on the key owner's faction.
+pub struct FactionEndpoint {
+    client: TornClient,
+}
+
+impl FactionEndpoint {
+    pub(crate) fn new(client: TornClient) -> Self {
+        Self { client }
+    }
+
+    /// Scopes subsequent calls to another faction: `/faction/{id}/...`.
+    ///
+    /// Accepts anything convertible to a [`FactionId`]; see
+    /// [`crate::endpoints::UserEndpoint::id`] for the conversion rules.
+    pub fn id(&self, id: impl Into<FactionId>) -> FactionIdContext {
+        FactionIdContext {
+            client: self.client.clone(),
+            id: id.into(),
+        }
+    }
+
+    /// Fetches basic data for several factions concurrently, mirroring
+    /// [`crate::endpoints::UserEndpoint::with_ids`].
+    pub async fn with_ids(&self, ids: &[u64]) -> HashMap<u64, Result<FactionBasic>> {
+        self.with_ids_concurrency(ids, super::DEFAULT_LOOKUP_CONCURRENCY)
+            .await
+    }
+
+    /// Like [`FactionEndpoint::with_ids`] with an explicit concurrency bound.
+    pub async fn with_ids_concurrency(
+        &self,
+        ids: &[u64],
+        concurrency: usize,
+    ) -> HashMap<u64, Result<FactionBasic>> {
+        futures_util::stream::iter(ids.iter().map(|&id| {
+            let context = self.id(id);
+            async move { (id, context.basic().await) }
+        }))
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await
+    }
+
+    /// `GET /faction/basic`
+    pub async fn basic(&self) -> Result<FactionBasic> {
+        let response: BasicResponse = self.client.get("/faction/basic", &[]).await?;
+        Ok(response.basic)
+    }
+
+    /// [`FactionEndpoint::basic`] with per-request options.
+    pub async fn basic_with_options(&self, options: &RequestOptions) -> Result<FactionBasic> {
+        let response: BasicResponse = self
+            .client
+            .get_with_options("/faction/basic", &[], options)
+            .await?;
+        Ok(response.basic)
+    }
+
+    /// `GET /faction/members`
+    pub async fn members(&self) -> Result<Vec<FactionMember>> {
+        let response: MembersResponse = self.client.get("/faction/members", &[]).await?;
+        Ok(response.members)
+    }
+
+    /// `GET /faction/hof`
+    ///
+    /// Non-paginated; returns the hall-of-fame block directly.
+    pub async fn hof(&self) -> Result<FactionHof> {
+        #[derive(Deserialize)]
+        struct Response {
+            hof: FactionHof,
+        }
+        let response: Response = self.client.get("/faction/hof", &[]).await?;
+        Ok(response.hof)
+    }
+
+    /// `GET /faction/positions`
+    ///
+    /// Non-paginated; returns the position list directly.
+    pub async fn positions(&self) -> Result<Vec<FactionPosition>> {
+        #[derive(Deserialize)]
+        struct Response {
+            positions: Vec<FactionPosition>,
+        }
+        let response: Response = self.client.get("/faction/positions", &[]).await?;
+        Ok(response.positions)
+    }
+
+    /// Fetches current rackets and the territory ownership map concurrently
+    /// and joins them: each racket is paired with the territory hosting it
+    /// (coordinates, owner faction), the stitched view territory-war planners
+    /// start from.
+    pub async fn rackets_with_territories(&self) -> Result<Vec<RacketTerritory>> {
+        let torn = TornEndpoint::new(self.client.clone());
+        let (rackets, territories) = futures_util::try_join!(torn.rackets(), torn.territories())?;
+        let mut by_id: HashMap<String, Territory> = territories
+            .into_iter()
+            .map(|territory| (territory.id.clone(), territory))
+            .collect();
+        Ok(rackets
+            .into_iter()
+            .map(|racket| {
+                let territory = by_id.remove(&racket.territory);
+                RacketTerritory { racket, territory }
+            })
+            .collect())
+    }
+
+    /// `GET /faction/attacks`
+    pub async fn attacks(&self) -> Result<PaginatedResponse<Attack>> {
+        get_paged(&self.client, "/faction/attacks", &[]).await
+    }
+
+    /// [`FactionEndpoint::attacks`] with pagination parameters on the first
+    /// page; followed links keep whatever shape the server echoes back.
+    pub async fn attacks_with_params(
+        &self,
+        params: &super::PageParams,
+    ) -> Result<PaginatedResponse<Attack>> {
+        get_paged(&self.client, "/faction/attacks", &params.to_query()).await
+    }
+
+    /// [`FactionEndpoint::attacks`] with per-request options on the first
+    /// page.
+    pub async fn attacks_with_options(
+        &self,
+        options: &RequestOptions,
+    ) -> Result<PaginatedResponse<Attack>> {
+        super::get_paged_with_options(&self.client, "/faction/attacks", &[], options).await
+    }
+
+    /// Streams every faction attack in `[from, to)`, flattened across all
+    /// pages in that window; mirrors
+    /// [`crate::endpoints::UserEndpoint::attacks_between`].
+    pub async fn attacks_between(&self, from: i64, to: i64) -> Result<ItemStream<Attack>> {
+        super::get_items_between(&self.client, "/faction/attacks", from, to, &[]).await
+    }
+
+    /// Streams every news entry in `[from, to)` for one category (e.g.
+    /// `"attack"`, `"armoryAction"`), flattened across all pages.
+    pub async fn news_between(
+        &self,
+        from: i64,
+        to: i64,
+        category: &str,
+    ) -> Result<ItemStream<FactionNews>> {
+        let extra = [("cat", category.to_owned())];
+        super::get_items_between(&self.client, "/faction/news", from, to, &extra).await
+    }
+
+    /// Fetches every faction attack in `[from, to)` using time-partitioned
+    /// concurrent requests; see
+    /// [`crate::endpoints::UserEndpoint::attacks_partitioned`].
+    pub async fn attacks_partitioned(
+        &self,
+        from: i64,
+        to: i64,
+        segments: usize,
+    ) -> Result<Vec<Attack>> {
+        super::get_paged_partitioned(
+            &self.client,
+            "/faction/attacks",
+            from,
+            to,
+            segments,
+            |a: &Attack| a.started,
+        )
+        .await
+    }
+
+    /// `GET /faction/revives`
+    pub async fn revives(&self) -> Result<PaginatedResponse<Revive>> {
+        get_paged(&self.client, "/faction/revives", &[]).await
+    }
+
+    /// [`FactionEndpoint::revives`] with pagination parameters on the first
+    /// page.
+    pub async fn revives_with_params(
+        &self,
+        params: &super::PageParams,
+    ) -> Result<PaginatedResponse<Revive>> {
+        get_paged(&self.client, "/faction/revives", &params.to_query()).await
+    }
+
+    /// `GET /faction/news`
+    pub async fn news(&self) -> Result<PaginatedResponse<FactionNews>> {
+        get_paged(&self.client, "/faction/news", &[]).await
+    }
+
+    /// [`FactionEndpoint::news`] with pagination parameters on the first
+    /// page.
+    pub async fn news_with_params(
+        &self,
+        params: &super::PageParams,
+    ) -> Result<PaginatedResponse<FactionNews>> {
+        get_paged(&self.client, "/faction/news", &params.to_query()).await
+    }
+}
+
+/// Handle for `/faction/{id}` routes.
+pub struct FactionIdContext {
+    client: TornClient,
+    id: FactionId,
+}
+
+impl FactionIdContext {
+    /// `GET /faction/{id}/basic`
+    pub async fn basic(&self) -> Result<FactionBasic> {
+        let path = format!("/faction/{}/basic", self.id.get()?);
+        let response: BasicResponse = self.client.get(&path, &[]).await?;
+        Ok(response.basic)
+    }
+
+    /// `GET /faction/{id}/members`
+    pub async fn members(&self) -> Result<Vec<FactionMember>> {
+        let path = format!("/faction/{}/members", self.id.get()?);
+        let response: MembersResponse = self.client.get(&path, &[]).await?;
+        Ok(response.members)
+    }
+}
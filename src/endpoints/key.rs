@@ -0,0 +1,28 @@
+//! Handle for the `/key` section.
+
+use serde::Deserialize;
+
+use crate::client::TornClient;
+use crate::models::key::KeyInfo;
+use crate::Result;
+
+/// Handle for `/key` routes (introspection of the key in use).
+pub struct KeyEndpoint {
+    client: TornClient,
+}
+
+impl KeyEndpoint {
+    pub(crate) fn new(client: TornClient) -> Self {
+        Self { client }
+    }
+
+    /// `GET /key/info`
+    pub async fn info(&self) -> Result<KeyInfo> {
+        #[derive(Deserialize)]
+        struct Response {
+            info: KeyInfo,
+        }
+        let response: Response = self.client.get("/key/info", &[]).await?;
+        Ok(response.info)
+    }
+}
@@ -0,0 +1,47 @@
+//! Handle for the `/market` section.
+
+use crate::client::TornClient;
+use crate::ids::ItemId;
+use crate::models::market::ItemMarket;
+use crate::Result;
+
+/// Handle for `/market` routes.
+pub struct MarketEndpoint {
+    client: TornClient,
+}
+
+impl MarketEndpoint {
+    pub(crate) fn new(client: TornClient) -> Self {
+        Self { client }
+    }
+
+    /// Scopes subsequent calls to a specific item: `/market/{id}/...`.
+    ///
+    /// Accepts anything convertible to an [`ItemId`]; see
+    /// [`crate::endpoints::UserEndpoint::id`] for the conversion rules.
+    pub fn item(&self, item_id: impl Into<ItemId>) -> MarketItemContext {
+        MarketItemContext {
+            client: self.client.clone(),
+            item_id: item_id.into(),
+        }
+    }
+}
+
+/// Handle for `/market/{id}` routes.
+pub struct MarketItemContext {
+    client: TornClient,
+    item_id: ItemId,
+}
+
+impl MarketItemContext {
+    /// `GET /market/{id}/itemmarket`
+    pub async fn itemmarket(&self) -> Result<ItemMarket> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            itemmarket: ItemMarket,
+        }
+        let path = format!("/market/{}/itemmarket", self.item_id.get()?);
+        let response: Response = self.client.get(&path, &[]).await?;
+        Ok(response.itemmarket)
+    }
+}
@@ -0,0 +1,33 @@
+//! Handle for the `/racing` section.
+
+use crate::client::TornClient;
+use crate::models::racing::Race;
+use crate::pagination::PaginatedResponse;
+use crate::Result;
+
+use super::get_paged;
+
+/// Handle for `/racing` routes.
+pub struct RacingEndpoint {
+    client: TornClient,
+}
+
+impl RacingEndpoint {
+    pub(crate) fn new(client: TornClient) -> Self {
+        Self { client }
+    }
+
+    /// `GET /racing/races`
+    pub async fn races(&self) -> Result<PaginatedResponse<Race>> {
+        get_paged(&self.client, "/racing/races", &[]).await
+    }
+
+    /// [`RacingEndpoint::races`] with pagination parameters on the first
+    /// page.
+    pub async fn races_with_params(
+        &self,
+        params: &super::PageParams,
+    ) -> Result<PaginatedResponse<Race>> {
+        get_paged(&self.client, "/racing/races", &params.to_query()).await
+    }
+}
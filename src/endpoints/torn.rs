@@ -0,0 +1,110 @@
+//! Handle for the `/torn` section.
+
+use crate::client::TornClient;
+use crate::models::torn::{Honor, Item, LogCategory, LogType, Medal, Racket, Territory, TornCrime};
+use crate::Result;
+
+/// Handle for `/torn` routes (game-wide reference data).
+pub struct TornEndpoint {
+    client: TornClient,
+}
+
+impl TornEndpoint {
+    pub(crate) fn new(client: TornClient) -> Self {
+        Self { client }
+    }
+
+    /// `GET /torn/timestamp` — the server's current unix time.
+    ///
+    /// Non-paginated; returns the timestamp directly. Every call also feeds
+    /// the client's clock skew estimate (see [`TornClient::torn_now`]).
+    pub async fn timestamp(&self) -> Result<i64> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            timestamp: i64,
+        }
+        let response: Response = self.client.get("/torn/timestamp", &[]).await?;
+        self.client.record_server_time(response.timestamp);
+        Ok(response.timestamp)
+    }
+
+    /// `GET /torn/logcategories`
+    pub async fn log_categories(&self) -> Result<Vec<LogCategory>> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            logcategories: Vec<LogCategory>,
+        }
+        let response: Response = self.client.get("/torn/logcategories", &[]).await?;
+        Ok(response.logcategories)
+    }
+
+    /// `GET /torn/logtypes`
+    pub async fn log_types(&self) -> Result<Vec<LogType>> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            logtypes: Vec<LogType>,
+        }
+        let response: Response = self.client.get("/torn/logtypes", &[]).await?;
+        Ok(response.logtypes)
+    }
+
+    /// `GET /torn/items` — the full item catalog.
+    pub async fn items(&self) -> Result<Vec<Item>> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            items: Vec<Item>,
+        }
+        let response: Response = self.client.get("/torn/items", &[]).await?;
+        Ok(response.items)
+    }
+
+    /// `GET /torn/rackets`
+    pub async fn rackets(&self) -> Result<Vec<Racket>> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            rackets: Vec<Racket>,
+        }
+        let response: Response = self.client.get("/torn/rackets", &[]).await?;
+        Ok(response.rackets)
+    }
+
+    /// `GET /torn/territory` — the full territory ownership map.
+    pub async fn territories(&self) -> Result<Vec<Territory>> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            territory: Vec<Territory>,
+        }
+        let response: Response = self.client.get("/torn/territory", &[]).await?;
+        Ok(response.territory)
+    }
+
+    /// `GET /torn/crimes`
+    pub async fn crimes(&self) -> Result<Vec<TornCrime>> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            crimes: Vec<TornCrime>,
+        }
+        let response: Response = self.client.get("/torn/crimes", &[]).await?;
+        Ok(response.crimes)
+    }
+
+    /// `GET /torn/honors`
+    pub async fn honors(&self) -> Result<Vec<Honor>> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            honors: Vec<Honor>,
+        }
+        let response: Response = self.client.get("/torn/honors", &[]).await?;
+        Ok(response.honors)
+    }
+
+    /// `GET /torn/medals`
+    pub async fn medals(&self) -> Result<Vec<Medal>> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            medals: Vec<Medal>,
+        }
+        let response: Response = self.client.get("/torn/medals", &[]).await?;
+        Ok(response.medals)
+    }
+}
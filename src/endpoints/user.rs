@@ -0,0 +1,173 @@
+//! Handle for the `/user` section.
+
+use std::collections::HashMap;
+
+use futures_util::StreamExt;
+use serde::Deserialize;
+
+use crate::client::{RequestOptions, TornClient};
+use crate::ids::UserId;
+use crate::models::user::{Attack, Revive, UserEvent, UserProfile};
+use crate::pagination::{ItemStream, PaginatedResponse};
+use crate::Result;
+
+use super::get_paged;
+
+#[derive(Deserialize)]
+struct ProfileResponse {
+    profile: UserProfile,
+}
+
+/// Handle for `/user` routes on the key owner.
+pub struct UserEndpoint {
+    client: TornClient,
+}
+
+impl UserEndpoint {
+    pub(crate) fn new(client: TornClient) -> Self {
+        Self { client }
+    }
+
+    /// Scopes subsequent calls to another player: `/user/{id}/...`.
+    ///
+    /// Accepts anything convertible to a [`UserId`], including numeric
+    /// strings; an unparseable ID fails at request time with
+    /// [`crate::TornError::InvalidParams`].
+    pub fn id(&self, id: impl Into<UserId>) -> UserIdContext {
+        UserIdContext {
+            client: self.client.clone(),
+            id: id.into(),
+        }
+    }
+
+    /// Fetches profiles for several players concurrently (the API has no
+    /// server-side batch endpoint). Requests fan out with bounded
+    /// concurrency; each ID maps to its own result so one failed lookup does
+    /// not discard the rest.
+    pub async fn with_ids(&self, ids: &[u64]) -> HashMap<u64, Result<UserProfile>> {
+        self.with_ids_concurrency(ids, super::DEFAULT_LOOKUP_CONCURRENCY)
+            .await
+    }
+
+    /// Like [`UserEndpoint::with_ids`] with an explicit concurrency bound.
+    pub async fn with_ids_concurrency(
+        &self,
+        ids: &[u64],
+        concurrency: usize,
+    ) -> HashMap<u64, Result<UserProfile>> {
+        futures_util::stream::iter(ids.iter().map(|&id| {
+            let context = self.id(id);
+            async move { (id, context.profile().await) }
+        }))
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await
+    }
+
+    /// `GET /user/profile`
+    pub async fn profile(&self) -> Result<UserProfile> {
+        let response: ProfileResponse = self.client.get("/user/profile", &[]).await?;
+        Ok(response.profile)
+    }
+
+    /// [`UserEndpoint::profile`] with per-request options.
+    pub async fn profile_with_options(&self, options: &RequestOptions) -> Result<UserProfile> {
+        let response: ProfileResponse = self
+            .client
+            .get_with_options("/user/profile", &[], options)
+            .await?;
+        Ok(response.profile)
+    }
+
+    /// `GET /user/attacks` — the key owner's most recent attacks.
+    pub async fn attacks(&self) -> Result<PaginatedResponse<Attack>> {
+        get_paged(&self.client, "/user/attacks", &[]).await
+    }
+
+    /// [`UserEndpoint::attacks`] with pagination parameters on the first
+    /// page; followed links keep whatever shape the server echoes back.
+    pub async fn attacks_with_params(
+        &self,
+        params: &super::PageParams,
+    ) -> Result<PaginatedResponse<Attack>> {
+        get_paged(&self.client, "/user/attacks", &params.to_query()).await
+    }
+
+    /// [`UserEndpoint::attacks`] with per-request options on the first page.
+    pub async fn attacks_with_options(
+        &self,
+        options: &RequestOptions,
+    ) -> Result<PaginatedResponse<Attack>> {
+        super::get_paged_with_options(&self.client, "/user/attacks", &[], options).await
+    }
+
+    /// Streams every attack in `[from, to)`, flattened across all pages in
+    /// that window — the one-liner for the most common attack-history query.
+    pub async fn attacks_between(&self, from: i64, to: i64) -> Result<ItemStream<Attack>> {
+        super::get_items_between(&self.client, "/user/attacks", from, to, &[]).await
+    }
+
+    /// Fetches every attack in `[from, to)` by splitting the range into
+    /// `segments` sub-ranges pulled concurrently (one pool key each) and
+    /// merging the results in ascending start-time order. Much faster than
+    /// walking pages serially when several keys are configured.
+    pub async fn attacks_partitioned(
+        &self,
+        from: i64,
+        to: i64,
+        segments: usize,
+    ) -> Result<Vec<Attack>> {
+        super::get_paged_partitioned(
+            &self.client,
+            "/user/attacks",
+            from,
+            to,
+            segments,
+            |a: &Attack| a.started,
+        )
+        .await
+    }
+
+    /// `GET /user/revives`
+    pub async fn revives(&self) -> Result<PaginatedResponse<Revive>> {
+        get_paged(&self.client, "/user/revives", &[]).await
+    }
+
+    /// [`UserEndpoint::revives`] with pagination parameters on the first
+    /// page.
+    pub async fn revives_with_params(
+        &self,
+        params: &super::PageParams,
+    ) -> Result<PaginatedResponse<Revive>> {
+        get_paged(&self.client, "/user/revives", &params.to_query()).await
+    }
+
+    /// `GET /user/events`
+    pub async fn events(&self) -> Result<PaginatedResponse<UserEvent>> {
+        get_paged(&self.client, "/user/events", &[]).await
+    }
+
+    /// [`UserEndpoint::events`] with pagination parameters on the first
+    /// page.
+    pub async fn events_with_params(
+        &self,
+        params: &super::PageParams,
+    ) -> Result<PaginatedResponse<UserEvent>> {
+        get_paged(&self.client, "/user/events", &params.to_query()).await
+    }
+}
+
+/// Handle for `/user/{id}` routes.
+pub struct UserIdContext {
+    client: TornClient,
+    id: UserId,
+}
+
+impl UserIdContext {
+    /// `GET /user/{id}/profile`
+    pub async fn profile(&self) -> Result<UserProfile> {
+        let path = format!("/user/{}/profile", self.id.get()?);
+        let response: ProfileResponse = self.client.get(&path, &[]).await?;
+        Ok(response.profile)
+    }
+}
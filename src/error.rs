@@ -0,0 +1,206 @@
+//! Error types returned by the client.
+
+use serde::Deserialize;
+
+use crate::models::key::AccessLevel;
+
+/// Error codes returned by the Torn API inside the `error` envelope.
+///
+/// The numeric codes are documented at
+/// <https://www.torn.com/swagger.php> under "Error codes".
+pub mod codes {
+    pub const UNKNOWN_ERROR: u16 = 0;
+    pub const KEY_EMPTY: u16 = 1;
+    pub const INCORRECT_KEY: u16 = 2;
+    pub const WRONG_TYPE: u16 = 3;
+    pub const WRONG_FIELDS: u16 = 4;
+    pub const TOO_MANY_REQUESTS: u16 = 5;
+    pub const INCORRECT_ID: u16 = 6;
+    pub const INCORRECT_ID_ENTITY_RELATION: u16 = 7;
+    pub const IP_BLOCK: u16 = 8;
+    pub const API_DISABLED: u16 = 9;
+    pub const KEY_OWNER_IN_FEDERAL_JAIL: u16 = 10;
+    pub const KEY_CHANGE_ERROR: u16 = 11;
+    pub const KEY_READ_ERROR: u16 = 12;
+    pub const KEY_TEMPORARILY_DISABLED: u16 = 13;
+    pub const DAILY_READ_LIMIT_REACHED: u16 = 14;
+    pub const TEMPORARY_ERROR: u16 = 15;
+    pub const ACCESS_LEVEL_TOO_LOW: u16 = 16;
+    pub const BACKEND_ERROR: u16 = 17;
+    pub const API_KEY_PAUSED: u16 = 18;
+    pub const MIGRATION_ERROR: u16 = 19;
+
+    /// Whether `code` condemns the key itself — incorrect, owner inactive,
+    /// or disabled — as opposed to a problem with the request.
+    pub fn is_key_invalid(code: u16) -> bool {
+        matches!(
+            code,
+            INCORRECT_KEY | KEY_OWNER_IN_FEDERAL_JAIL | KEY_TEMPORARILY_DISABLED
+        )
+    }
+    pub const RACE_NOT_FINISHED: u16 = 20;
+    pub const INCORRECT_CATEGORY: u16 = 21;
+    pub const SELECTION_ONLY_IN_API_V1: u16 = 22;
+    pub const SELECTION_ONLY_IN_API_V2: u16 = 23;
+}
+
+/// The `error` object the Torn API embeds in an otherwise 200 response.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiErrorBody {
+    pub code: u16,
+    #[serde(rename = "error")]
+    pub message: String,
+}
+
+/// Wire shape of an error response: `{"error": {"code": .., "error": ".."}}`.
+/// Unknown fields are denied so probing a success body for this shape fails
+/// at its first key instead of scanning the whole payload.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ApiErrorEnvelope {
+    pub(crate) error: ApiErrorBody,
+}
+
+/// Unified error type for everything that can go wrong while talking to Torn.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum TornError {
+    /// Transport-level failure (connect, TLS, body read, ...).
+    #[error("http error: {0}")]
+    Http(reqwest::Error),
+
+    /// The request or connect timeout elapsed; see
+    /// [`crate::TornClientConfig::timeout`].
+    #[error("request timed out: {0}")]
+    Timeout(reqwest::Error),
+
+    /// The API answered with its own error envelope.
+    #[error("torn api error {code}: {message}", code = .0.code, message = .0.message)]
+    Api(ApiErrorBody),
+
+    /// The response body did not match the expected model.
+    #[error("failed to deserialize response: {0}")]
+    Deserialize(#[from] serde_json::Error),
+
+    /// The local rate limiter refused the request: either
+    /// `RateLimitMode::Error` found the window full, or an `AutoDelay` wait
+    /// exceeded [`crate::TornClientConfig::max_rate_limit_wait`].
+    #[error("local rate limit exceeded for key")]
+    RateLimited {
+        /// The wait `AutoDelay` would have imposed, when known.
+        wait: Option<std::time::Duration>,
+    },
+
+    /// No usable API key is available in the pool.
+    #[error("no api key available")]
+    NoKeyAvailable,
+
+    /// The client is paused via [`crate::TornClient::pause`] and is
+    /// configured to fail new requests instead of queueing them.
+    #[error("client is paused")]
+    Paused,
+
+    /// The client is refusing traffic for the remainder of an IP-block
+    /// cool-off (the API returned error code 8 and the pause mode is
+    /// [`crate::client::PauseMode::Error`]).
+    #[error("ip block cool-off active for another {remaining:?}")]
+    CoolingOff {
+        /// Time left until traffic resumes.
+        remaining: std::time::Duration,
+    },
+
+    /// The client has been shut down via [`crate::TornClient::shutdown`].
+    #[error("client is shut down")]
+    ShutDown,
+
+    /// The request was cancelled through a
+    /// [`CancellationToken`](tokio_util::sync::CancellationToken); see
+    /// [`crate::RequestOptions::cancellation_token`] and
+    /// [`crate::TornClientConfig::cancellation_token`].
+    #[error("request cancelled")]
+    Cancelled,
+
+    /// A request parameter could not be converted into a valid value.
+    #[error("invalid parameters: {0}")]
+    InvalidParams(String),
+
+    /// The pre-flight guard refused a request the key cannot serve.
+    #[error("insufficient access for {required} (key level: {actual:?})")]
+    InsufficientAccess {
+        /// The `section/selection` the request needed.
+        required: String,
+        /// The key's access level, when the server reported a known one.
+        actual: Option<AccessLevel>,
+    },
+
+    /// The configured `comment` parameter violates Torn's rules.
+    #[error("invalid comment: {0}")]
+    InvalidComment(String),
+
+    /// A pagination link returned by the API could not be parsed.
+    #[error("invalid pagination url: {0}")]
+    InvalidPaginationUrl(String),
+
+    /// Any failure from a call made with request IDs enabled, wrapping the
+    /// underlying error so the correlation ID travels with it end to end;
+    /// see [`crate::TornClientConfig::request_id_header`].
+    #[error("request {id}: {source}")]
+    WithRequestId {
+        /// The correlation ID attached to the failing call.
+        id: String,
+        /// The underlying failure.
+        source: Box<TornError>,
+    },
+}
+
+impl From<reqwest::Error> for TornError {
+    fn from(error: reqwest::Error) -> Self {
+        // v1 routes authenticate via a `key` query parameter; strip the URL
+        // from any error carrying one so the key cannot surface through
+        // `Debug`/`Display` output of transport failures.
+        let error = match error.url() {
+            Some(url) if url.query_pairs().any(|(name, _)| name == "key") => error.without_url(),
+            _ => error,
+        };
+        if error.is_timeout() {
+            TornError::Timeout(error)
+        } else {
+            TornError::Http(error)
+        }
+    }
+}
+
+impl TornError {
+    /// Returns the Torn API error code if this is an [`TornError::Api`] error.
+    pub fn api_code(&self) -> Option<u16> {
+        match self {
+            TornError::Api(body) => Some(body.code),
+            TornError::WithRequestId { source, .. } => source.api_code(),
+            _ => None,
+        }
+    }
+
+    /// The correlation ID of the failing call, when request IDs are enabled;
+    /// see [`crate::TornClientConfig::request_id_header`].
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            TornError::WithRequestId { id, .. } => Some(id),
+            _ => None,
+        }
+    }
+
+    /// Whether retrying the same request later could plausibly succeed.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            TornError::Timeout(_) => true,
+            TornError::Http(e) => e.is_connect(),
+            TornError::Api(body) => matches!(
+                body.code,
+                codes::TOO_MANY_REQUESTS | codes::TEMPORARY_ERROR | codes::BACKEND_ERROR
+            ),
+            TornError::RateLimited { .. } => true,
+            TornError::WithRequestId { source, .. } => source.is_transient(),
+            _ => false,
+        }
+    }
+}
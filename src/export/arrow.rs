@@ -0,0 +1,221 @@
+//! Arrow and Parquet export (`arrow` feature).
+//!
+//! Converts paginated collections into Arrow record batches and writes them
+//! as Parquet, so pulls can be loaded straight into DuckDB, pandas or polars
+//! without an intermediate CSV hop.
+
+use std::io::Write;
+use std::sync::Arc;
+
+use arrow_array::builder::{
+    BooleanBuilder, Float64Builder, Int64Builder, StringBuilder, UInt32Builder, UInt64Builder,
+};
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef};
+use parquet::arrow::ArrowWriter;
+use parquet::errors::ParquetError;
+
+use crate::models::market::ItemMarketListing;
+use crate::models::racing::Race;
+use crate::models::user::Attack;
+use crate::pagination::PaginatedResponse;
+
+/// A model whose collections can be converted into an Arrow [`RecordBatch`].
+pub trait ToArrow: Sized {
+    /// The Arrow schema of the flattened record.
+    fn schema() -> SchemaRef;
+
+    /// Builds one record batch from a slice of items.
+    fn to_record_batch(items: &[Self]) -> Result<RecordBatch, ArrowError>;
+}
+
+/// Writes `items` to `writer` as a single-row-group Parquet file.
+pub fn write_parquet<T: ToArrow, W: Write + Send>(
+    items: &[T],
+    writer: W,
+) -> Result<(), ParquetError> {
+    let batch = T::to_record_batch(items)?;
+    let mut writer = ArrowWriter::try_new(writer, T::schema(), None)?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+impl<T: ToArrow> PaginatedResponse<T> {
+    /// Converts this page's items into an Arrow record batch.
+    pub fn to_record_batch(&self) -> Result<RecordBatch, ArrowError> {
+        T::to_record_batch(&self.data)
+    }
+}
+
+impl ToArrow for Attack {
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("id", DataType::UInt64, false),
+            Field::new("started", DataType::Int64, false),
+            Field::new("ended", DataType::Int64, false),
+            Field::new("attacker_id", DataType::UInt64, true),
+            Field::new("attacker_name", DataType::Utf8, true),
+            Field::new("defender_id", DataType::UInt64, true),
+            Field::new("defender_name", DataType::Utf8, true),
+            Field::new("result", DataType::Utf8, false),
+            Field::new("respect_gain", DataType::Float64, false),
+            Field::new("respect_loss", DataType::Float64, false),
+            Field::new("chain", DataType::UInt32, false),
+            Field::new("is_stealthed", DataType::Boolean, false),
+        ]))
+    }
+
+    fn to_record_batch(items: &[Self]) -> Result<RecordBatch, ArrowError> {
+        let mut id = UInt64Builder::new();
+        let mut started = Int64Builder::new();
+        let mut ended = Int64Builder::new();
+        let mut attacker_id = UInt64Builder::new();
+        let mut attacker_name = StringBuilder::new();
+        let mut defender_id = UInt64Builder::new();
+        let mut defender_name = StringBuilder::new();
+        let mut result = StringBuilder::new();
+        let mut respect_gain = Float64Builder::new();
+        let mut respect_loss = Float64Builder::new();
+        let mut chain = UInt32Builder::new();
+        let mut is_stealthed = BooleanBuilder::new();
+        for attack in items {
+            id.append_value(attack.id);
+            started.append_value(attack.started);
+            ended.append_value(attack.ended);
+            attacker_id.append_option(attack.attacker.as_ref().and_then(|a| a.id));
+            attacker_name.append_option(attack.attacker.as_ref().and_then(|a| a.name.as_deref()));
+            defender_id.append_option(attack.defender.id);
+            defender_name.append_option(attack.defender.name.as_deref());
+            result.append_value(&attack.result);
+            respect_gain.append_value(attack.respect_gain);
+            respect_loss.append_value(attack.respect_loss);
+            chain.append_value(attack.chain);
+            is_stealthed.append_value(attack.is_stealthed);
+        }
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(id.finish()),
+            Arc::new(started.finish()),
+            Arc::new(ended.finish()),
+            Arc::new(attacker_id.finish()),
+            Arc::new(attacker_name.finish()),
+            Arc::new(defender_id.finish()),
+            Arc::new(defender_name.finish()),
+            Arc::new(result.finish()),
+            Arc::new(respect_gain.finish()),
+            Arc::new(respect_loss.finish()),
+            Arc::new(chain.finish()),
+            Arc::new(is_stealthed.finish()),
+        ];
+        RecordBatch::try_new(Self::schema(), columns)
+    }
+}
+
+impl ToArrow for Race {
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("id", DataType::UInt64, false),
+            Field::new("title", DataType::Utf8, false),
+            Field::new("track_id", DataType::UInt32, false),
+            Field::new("status", DataType::Utf8, false),
+            Field::new("laps", DataType::UInt32, false),
+            Field::new("participants", DataType::UInt32, false),
+            Field::new("start", DataType::Int64, false),
+            Field::new("end", DataType::Int64, false),
+        ]))
+    }
+
+    fn to_record_batch(items: &[Self]) -> Result<RecordBatch, ArrowError> {
+        let mut id = UInt64Builder::new();
+        let mut title = StringBuilder::new();
+        let mut track_id = UInt32Builder::new();
+        let mut status = StringBuilder::new();
+        let mut laps = UInt32Builder::new();
+        let mut participants = UInt32Builder::new();
+        let mut start = Int64Builder::new();
+        let mut end = Int64Builder::new();
+        for race in items {
+            id.append_value(race.id);
+            title.append_value(&race.title);
+            track_id.append_value(race.track_id);
+            status.append_value(&race.status);
+            laps.append_value(race.laps);
+            participants.append_value(race.participants.current);
+            start.append_value(race.schedule.start);
+            end.append_value(race.schedule.end);
+        }
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(id.finish()),
+            Arc::new(title.finish()),
+            Arc::new(track_id.finish()),
+            Arc::new(status.finish()),
+            Arc::new(laps.finish()),
+            Arc::new(participants.finish()),
+            Arc::new(start.finish()),
+            Arc::new(end.finish()),
+        ];
+        RecordBatch::try_new(Self::schema(), columns)
+    }
+}
+
+impl ToArrow for ItemMarketListing {
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("id", DataType::UInt64, false),
+            Field::new("price", DataType::Int64, false),
+            Field::new("amount", DataType::UInt32, false),
+        ]))
+    }
+
+    fn to_record_batch(items: &[Self]) -> Result<RecordBatch, ArrowError> {
+        let mut id = UInt64Builder::new();
+        let mut price = Int64Builder::new();
+        let mut amount = UInt32Builder::new();
+        for listing in items {
+            id.append_value(listing.id);
+            price.append_value(listing.price);
+            amount.append_value(listing.amount);
+        }
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(id.finish()),
+            Arc::new(price.finish()),
+            Arc::new(amount.finish()),
+        ];
+        RecordBatch::try_new(Self::schema(), columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listings() -> Vec<ItemMarketListing> {
+        vec![
+            ItemMarketListing {
+                id: 1,
+                price: 850_000,
+                amount: 3,
+            },
+            ItemMarketListing {
+                id: 2,
+                price: 849_999,
+                amount: 1,
+            },
+        ]
+    }
+
+    #[test]
+    fn listings_batch_matches_schema() {
+        let batch = ItemMarketListing::to_record_batch(&listings()).unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema(), ItemMarketListing::schema());
+    }
+
+    #[test]
+    fn parquet_output_has_magic_bytes() {
+        let mut out = Vec::new();
+        write_parquet(&listings(), &mut out).unwrap();
+        assert_eq!(&out[..4], b"PAR1");
+        assert_eq!(&out[out.len() - 4..], b"PAR1");
+    }
+}
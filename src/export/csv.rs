@@ -0,0 +1,227 @@
+//! CSV export for list-shaped responses (`csv` feature).
+//!
+//! Faction admin happens in spreadsheets; these helpers flatten the nested
+//! response models into one row per item so lists can be dropped straight
+//! into Sheets or Excel:
+//!
+//! ```no_run
+//! # async fn run(client: &torn_client::TornClient) -> Result<(), Box<dyn std::error::Error>> {
+//! use torn_client::export::csv::ToCsv;
+//!
+//! let members = client.faction().members().await?;
+//! members.to_csv(std::fs::File::create("members.csv")?)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::Write;
+
+use crate::models::faction::FactionMember;
+use crate::models::market::ItemMarketListing;
+use crate::models::user::{Attack, Revive, UserEvent};
+use crate::pagination::PaginatedResponse;
+
+/// A model that can be flattened into one CSV row.
+pub trait CsvRecord {
+    /// Column names, written once as the header row.
+    const HEADERS: &'static [&'static str];
+
+    /// The row for this item, in [`CsvRecord::HEADERS`] order.
+    fn record(&self) -> Vec<String>;
+}
+
+/// Writes any collection of [`CsvRecord`]s as CSV, header row included.
+pub trait ToCsv {
+    fn to_csv<W: Write>(&self, writer: W) -> Result<(), csv::Error>;
+}
+
+impl<R: CsvRecord> ToCsv for [R] {
+    fn to_csv<W: Write>(&self, writer: W) -> Result<(), csv::Error> {
+        let mut writer = csv::Writer::from_writer(writer);
+        writer.write_record(R::HEADERS)?;
+        for item in self {
+            writer.write_record(item.record())?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl<R: CsvRecord> ToCsv for Vec<R> {
+    fn to_csv<W: Write>(&self, writer: W) -> Result<(), csv::Error> {
+        self.as_slice().to_csv(writer)
+    }
+}
+
+impl<R: CsvRecord> ToCsv for PaginatedResponse<R> {
+    fn to_csv<W: Write>(&self, writer: W) -> Result<(), csv::Error> {
+        self.data.to_csv(writer)
+    }
+}
+
+fn opt<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map(ToString::to_string).unwrap_or_default()
+}
+
+impl CsvRecord for Attack {
+    const HEADERS: &'static [&'static str] = &[
+        "id",
+        "started",
+        "ended",
+        "attacker_id",
+        "attacker_name",
+        "attacker_faction",
+        "defender_id",
+        "defender_name",
+        "defender_faction",
+        "result",
+        "respect_gain",
+        "respect_loss",
+        "chain",
+        "stealthed",
+    ];
+
+    fn record(&self) -> Vec<String> {
+        let attacker = self.attacker.as_ref();
+        vec![
+            self.id.to_string(),
+            self.started.to_string(),
+            self.ended.to_string(),
+            opt(&attacker.and_then(|a| a.id)),
+            opt(&attacker.and_then(|a| a.name.clone())),
+            opt(&attacker
+                .and_then(|a| a.faction.as_ref())
+                .map(|f| f.name.clone())),
+            opt(&self.defender.id),
+            opt(&self.defender.name),
+            opt(&self.defender.faction.as_ref().map(|f| f.name.clone())),
+            self.result.clone(),
+            self.respect_gain.to_string(),
+            self.respect_loss.to_string(),
+            self.chain.to_string(),
+            self.is_stealthed.to_string(),
+        ]
+    }
+}
+
+impl CsvRecord for Revive {
+    const HEADERS: &'static [&'static str] = &[
+        "id",
+        "timestamp",
+        "reviver_id",
+        "reviver_name",
+        "reviver_faction",
+        "target_id",
+        "target_name",
+        "target_faction",
+        "success_chance",
+        "result",
+    ];
+
+    fn record(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.timestamp.to_string(),
+            self.reviver.id.to_string(),
+            opt(&self.reviver.name),
+            opt(&self.reviver.faction.as_ref().map(|f| f.name.clone())),
+            self.target.id.to_string(),
+            opt(&self.target.name),
+            opt(&self.target.faction.as_ref().map(|f| f.name.clone())),
+            self.success_chance.to_string(),
+            self.result.clone(),
+        ]
+    }
+}
+
+impl CsvRecord for ItemMarketListing {
+    const HEADERS: &'static [&'static str] = &["id", "price", "amount"];
+
+    fn record(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.price.to_string(),
+            self.amount.to_string(),
+        ]
+    }
+}
+
+impl CsvRecord for FactionMember {
+    const HEADERS: &'static [&'static str] = &[
+        "id",
+        "name",
+        "level",
+        "position",
+        "days_in_faction",
+        "revivable",
+        "last_action",
+        "last_action_timestamp",
+        "status",
+        "status_until",
+    ];
+
+    fn record(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.name.clone(),
+            self.level.to_string(),
+            self.position.clone(),
+            self.days_in_faction.to_string(),
+            self.is_revivable.to_string(),
+            self.last_action.status.clone(),
+            self.last_action.timestamp.to_string(),
+            self.status.description.clone(),
+            opt(&self.status.until),
+        ]
+    }
+}
+
+impl CsvRecord for UserEvent {
+    const HEADERS: &'static [&'static str] = &["id", "timestamp", "event"];
+
+    fn record(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.timestamp.to_string(),
+            self.event.clone(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn listings_round_trip_with_header() {
+        let listings = vec![
+            ItemMarketListing {
+                id: 1,
+                price: 850_000,
+                amount: 3,
+            },
+            ItemMarketListing {
+                id: 2,
+                price: 849_999,
+                amount: 1,
+            },
+        ];
+        let mut out = Vec::new();
+        listings.to_csv(&mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text, "id,price,amount\n1,850000,3\n2,849999,1\n");
+    }
+
+    #[test]
+    fn events_with_commas_are_quoted() {
+        let events = vec![UserEvent {
+            id: "abc".into(),
+            timestamp: 1_700_000_000,
+            event: "You were attacked, and lost".into(),
+        }];
+        let mut out = Vec::new();
+        events.to_csv(&mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains("\"You were attacked, and lost\""));
+    }
+}
@@ -0,0 +1,171 @@
+//! JSON Lines streaming export.
+//!
+//! Bridges a [`PageStream`] into log pipelines and `jq`-based workflows:
+//! every item becomes one JSON line on any [`AsyncWrite`], written with
+//! backpressure (the next page is not fetched until the current one has been
+//! flushed downstream).
+
+use futures_util::StreamExt;
+use serde::Serialize;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::pagination::PageStream;
+use crate::TornError;
+
+/// Progress of a (possibly partial) JSON Lines export.
+///
+/// `resume_url` is the pagination cursor of the first page that has not been
+/// *completely* written; feed it back into a fresh request to continue an
+/// interrupted export. A failure mid-page resumes at that same page, so use
+/// `items` to trim the lines already written from its refetch.
+#[derive(Debug, Clone, Default)]
+pub struct JsonLinesProgress {
+    pub items: u64,
+    pub pages: u64,
+    pub resume_url: Option<String>,
+}
+
+/// Why a JSON Lines export stopped early.
+#[derive(Debug, thiserror::Error)]
+pub enum JsonLinesError {
+    /// The sink failed; `progress.resume_url` says where to pick up.
+    #[error("write failed after {} items: {source}", progress.items)]
+    Io {
+        source: std::io::Error,
+        progress: JsonLinesProgress,
+    },
+    /// A page fetch failed; `progress.resume_url` says where to pick up.
+    #[error("page fetch failed after {} pages: {source}", progress.pages)]
+    Fetch {
+        source: TornError,
+        progress: JsonLinesProgress,
+    },
+}
+
+/// Walks `stream` to exhaustion, writing one JSON line per item to `writer`.
+///
+/// Returns the final progress on success. On failure the error carries the
+/// progress reached so far, including a resumable cursor.
+pub async fn write_json_lines<T, W>(
+    mut stream: PageStream<T>,
+    writer: &mut W,
+) -> Result<JsonLinesProgress, JsonLinesError>
+where
+    T: Serialize,
+    W: AsyncWrite + Unpin,
+{
+    let mut progress = JsonLinesProgress::default();
+    while let Some(page) = stream.next().await {
+        let page = match page {
+            Ok(page) => page,
+            Err(source) => {
+                return Err(JsonLinesError::Fetch { source, progress });
+            }
+        };
+        for item in &page.data {
+            // serde_json::to_vec of a model we serialized ourselves cannot
+            // produce invalid output; treat failure as an io-level problem.
+            let mut line = serde_json::to_vec(item)
+                .map_err(std::io::Error::other)
+                .map_err(|source| JsonLinesError::Io {
+                    source,
+                    progress: progress.clone(),
+                })?;
+            line.push(b'\n');
+            if let Err(source) = writer.write_all(&line).await {
+                return Err(JsonLinesError::Io { source, progress });
+            }
+            progress.items += 1;
+        }
+        // Only a fully written page moves the cursor forward; advancing it
+        // before the item loop would make a mid-page failure resume past the
+        // unwritten remainder of this page.
+        progress.resume_url = page.next_url().map(str::to_owned);
+        progress.pages += 1;
+    }
+    if let Err(source) = writer.flush().await {
+        return Err(JsonLinesError::Io { source, progress });
+    }
+    progress.resume_url = None;
+    Ok(progress)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{TornClient, TornClientConfig};
+    use crate::pagination::{PaginatedResponse, PaginationMetadata};
+
+    #[tokio::test]
+    async fn single_page_writes_one_line_per_item() {
+        let page = PaginatedResponse::new(
+            vec![1u32, 2, 3],
+            PaginationMetadata::default(),
+            TornClient::new(TornClientConfig::new("k")),
+        );
+        let mut out = Vec::new();
+        let progress = write_json_lines(page.into_stream(), &mut out)
+            .await
+            .unwrap();
+        assert_eq!(progress.items, 3);
+        assert_eq!(progress.pages, 1);
+        assert!(progress.resume_url.is_none());
+        assert_eq!(String::from_utf8(out).unwrap(), "1\n2\n3\n");
+    }
+
+    /// Accepts a fixed number of writes, then fails.
+    struct FailingWriter {
+        remaining: usize,
+    }
+
+    impl AsyncWrite for FailingWriter {
+        fn poll_write(
+            mut self: std::pin::Pin<&mut Self>,
+            _cx: &mut std::task::Context<'_>,
+            buf: &[u8],
+        ) -> std::task::Poll<std::io::Result<usize>> {
+            if self.remaining == 0 {
+                return std::task::Poll::Ready(Err(std::io::Error::other("sink full")));
+            }
+            self.remaining -= 1;
+            std::task::Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_flush(
+            self: std::pin::Pin<&mut Self>,
+            _cx: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<std::io::Result<()>> {
+            std::task::Poll::Ready(Ok(()))
+        }
+
+        fn poll_shutdown(
+            self: std::pin::Pin<&mut Self>,
+            _cx: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<std::io::Result<()>> {
+            std::task::Poll::Ready(Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn mid_page_write_failure_resumes_at_the_partial_page() {
+        let mut metadata = PaginationMetadata::default();
+        metadata.links.next = Some("https://api.torn.com/v2/u2".to_owned());
+        let page = PaginatedResponse::new(
+            vec![1u32, 2, 3],
+            metadata,
+            TornClient::new(TornClientConfig::new("k")),
+        );
+        let mut out = FailingWriter { remaining: 1 };
+        let err = write_json_lines(page.into_stream(), &mut out)
+            .await
+            .unwrap_err();
+        let JsonLinesError::Io { progress, .. } = err else {
+            panic!("expected io error");
+        };
+        // One line made it out; the cursor must still point at this page —
+        // not its next link — so the unwritten remainder is refetched.
+        assert_eq!(progress.items, 1);
+        assert_eq!(progress.pages, 0);
+        assert!(progress.resume_url.is_none());
+    }
+}
@@ -0,0 +1,12 @@
+//! Exporting API responses into external data formats.
+//!
+//! Each format lives behind its own feature flag so the default build stays
+//! lean.
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "csv")]
+pub mod csv;
+pub mod jsonl;
+#[cfg(feature = "polars")]
+pub mod polars;
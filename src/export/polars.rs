@@ -0,0 +1,199 @@
+//! polars DataFrame conversions (`polars` feature).
+//!
+//! Turns the major list responses into [`DataFrame`]s for in-process
+//! analytics — group-bys over attack logs, rolling price averages — without
+//! hand-writing struct-to-column plumbing.
+
+use polars::prelude::{Column, DataFrame, PolarsResult};
+
+use crate::models::faction::FactionMember;
+use crate::models::market::ItemMarketListing;
+use crate::models::user::{Attack, Revive, UserEvent};
+use crate::pagination::PaginatedResponse;
+
+/// A collection that can be converted into a polars [`DataFrame`].
+pub trait IntoDataFrame {
+    fn into_dataframe(self) -> PolarsResult<DataFrame>;
+}
+
+impl<T> IntoDataFrame for PaginatedResponse<T>
+where
+    Vec<T>: IntoDataFrame,
+{
+    fn into_dataframe(self) -> PolarsResult<DataFrame> {
+        self.data.into_dataframe()
+    }
+}
+
+impl IntoDataFrame for Vec<Attack> {
+    fn into_dataframe(self) -> PolarsResult<DataFrame> {
+        let attacker_id: Vec<Option<u64>> = self
+            .iter()
+            .map(|a| a.attacker.as_ref().and_then(|p| p.id))
+            .collect();
+        let attacker_name: Vec<Option<&str>> = self
+            .iter()
+            .map(|a| a.attacker.as_ref().and_then(|p| p.name.as_deref()))
+            .collect();
+        DataFrame::new_infer_height(vec![
+            Column::new("id".into(), self.iter().map(|a| a.id).collect::<Vec<_>>()),
+            Column::new(
+                "started".into(),
+                self.iter().map(|a| a.started).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "ended".into(),
+                self.iter().map(|a| a.ended).collect::<Vec<_>>(),
+            ),
+            Column::new("attacker_id".into(), attacker_id),
+            Column::new("attacker_name".into(), attacker_name),
+            Column::new(
+                "defender_id".into(),
+                self.iter().map(|a| a.defender.id).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "result".into(),
+                self.iter().map(|a| a.result.as_str()).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "respect_gain".into(),
+                self.iter().map(|a| a.respect_gain).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "respect_loss".into(),
+                self.iter().map(|a| a.respect_loss).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "chain".into(),
+                self.iter().map(|a| a.chain).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "is_stealthed".into(),
+                self.iter().map(|a| a.is_stealthed).collect::<Vec<_>>(),
+            ),
+        ])
+    }
+}
+
+impl IntoDataFrame for Vec<Revive> {
+    fn into_dataframe(self) -> PolarsResult<DataFrame> {
+        DataFrame::new_infer_height(vec![
+            Column::new("id".into(), self.iter().map(|r| r.id).collect::<Vec<_>>()),
+            Column::new(
+                "timestamp".into(),
+                self.iter().map(|r| r.timestamp).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "reviver_id".into(),
+                self.iter().map(|r| r.reviver.id).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "target_id".into(),
+                self.iter().map(|r| r.target.id).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "success_chance".into(),
+                self.iter().map(|r| r.success_chance).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "result".into(),
+                self.iter().map(|r| r.result.as_str()).collect::<Vec<_>>(),
+            ),
+        ])
+    }
+}
+
+impl IntoDataFrame for Vec<UserEvent> {
+    fn into_dataframe(self) -> PolarsResult<DataFrame> {
+        DataFrame::new_infer_height(vec![
+            Column::new(
+                "id".into(),
+                self.iter().map(|e| e.id.as_str()).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "timestamp".into(),
+                self.iter().map(|e| e.timestamp).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "event".into(),
+                self.iter().map(|e| e.event.as_str()).collect::<Vec<_>>(),
+            ),
+        ])
+    }
+}
+
+impl IntoDataFrame for Vec<FactionMember> {
+    fn into_dataframe(self) -> PolarsResult<DataFrame> {
+        DataFrame::new_infer_height(vec![
+            Column::new("id".into(), self.iter().map(|m| m.id).collect::<Vec<_>>()),
+            Column::new(
+                "name".into(),
+                self.iter().map(|m| m.name.as_str()).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "level".into(),
+                self.iter().map(|m| m.level).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "position".into(),
+                self.iter().map(|m| m.position.as_str()).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "days_in_faction".into(),
+                self.iter().map(|m| m.days_in_faction).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "is_revivable".into(),
+                self.iter().map(|m| m.is_revivable).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "last_action".into(),
+                self.iter()
+                    .map(|m| m.last_action.timestamp)
+                    .collect::<Vec<_>>(),
+            ),
+        ])
+    }
+}
+
+impl IntoDataFrame for Vec<ItemMarketListing> {
+    fn into_dataframe(self) -> PolarsResult<DataFrame> {
+        DataFrame::new_infer_height(vec![
+            Column::new("id".into(), self.iter().map(|l| l.id).collect::<Vec<_>>()),
+            Column::new(
+                "price".into(),
+                self.iter().map(|l| l.price).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "amount".into(),
+                self.iter().map(|l| l.amount).collect::<Vec<_>>(),
+            ),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn listings_dataframe_has_expected_shape() {
+        let df = vec![
+            ItemMarketListing {
+                id: 1,
+                price: 850_000,
+                amount: 3,
+            },
+            ItemMarketListing {
+                id: 2,
+                price: 849_999,
+                amount: 1,
+            },
+        ]
+        .into_dataframe()
+        .unwrap();
+        assert_eq!(df.shape(), (2, 3));
+        let names: Vec<_> = df.get_column_names().iter().map(|n| n.as_str()).collect();
+        assert_eq!(names, vec!["id", "price", "amount"]);
+    }
+}
@@ -0,0 +1,210 @@
+//! Rolling API health tracking.
+//!
+//! Every completed request feeds a sliding window of outcomes; the client
+//! summarizes it through [`crate::TornClient::api_status`] so monitors and
+//! schedulers can back off globally during a Torn outage instead of each
+//! discovering it through their own failures.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::error::{codes, TornError};
+
+/// How far back outcomes count toward the health verdict.
+pub(crate) const HEALTH_WINDOW: Duration = Duration::from_secs(120);
+
+/// Below this many recent requests the verdict stays [`ApiHealth::Healthy`];
+/// one failed probe should not flip a mostly-idle client to `Down`.
+const MIN_SAMPLES: usize = 5;
+
+/// Outage-error ratio at which the API counts as degraded / down.
+const DEGRADED_RATIO: f64 = 0.1;
+const DOWN_RATIO: f64 = 0.5;
+
+/// Coarse classification of a failed request, keyed into the breakdown map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HealthErrorKind {
+    /// Connect, TLS, timeout or body-read failure.
+    Transport,
+    /// An API error envelope with this code.
+    Api(u16),
+    /// The body parsed as neither the expected model nor an error envelope.
+    Decode,
+}
+
+impl HealthErrorKind {
+    /// Whether this error plausibly indicates a server-side outage rather
+    /// than a caller mistake (bad key, bad ID, access level).
+    fn indicates_outage(self) -> bool {
+        match self {
+            HealthErrorKind::Transport | HealthErrorKind::Decode => true,
+            HealthErrorKind::Api(code) => matches!(
+                code,
+                codes::TOO_MANY_REQUESTS
+                    | codes::IP_BLOCK
+                    | codes::API_DISABLED
+                    | codes::TEMPORARY_ERROR
+                    | codes::BACKEND_ERROR
+            ),
+        }
+    }
+}
+
+/// Overall verdict derived from the recent outcome window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiHealth {
+    /// Errors are rare or absent.
+    Healthy,
+    /// A noticeable share of recent requests failed with outage-like errors.
+    Degraded,
+    /// Most recent requests failed with outage-like errors.
+    Down,
+}
+
+/// Snapshot returned by [`crate::TornClient::api_status`].
+#[derive(Debug, Clone)]
+pub struct ApiStatus {
+    /// The overall verdict.
+    pub health: ApiHealth,
+    /// Requests completing successfully inside the window.
+    pub successes: usize,
+    /// Requests failing inside the window, of any kind.
+    pub errors: usize,
+    /// Failure counts per kind inside the window.
+    pub breakdown: HashMap<HealthErrorKind, usize>,
+}
+
+/// Sliding window of request outcomes shared by all clones of a client.
+#[derive(Debug, Default)]
+pub(crate) struct HealthTracker {
+    outcomes: Mutex<VecDeque<(Instant, Option<HealthErrorKind>)>>,
+}
+
+impl HealthTracker {
+    pub(crate) fn record_success(&self) {
+        self.record(None);
+    }
+
+    pub(crate) fn record_error(&self, error: &TornError) {
+        let kind = match error {
+            TornError::Http(_) | TornError::Timeout(_) => HealthErrorKind::Transport,
+            TornError::Api(body) => HealthErrorKind::Api(body.code),
+            TornError::Deserialize(_) => HealthErrorKind::Decode,
+            // Locally-generated errors say nothing about the server.
+            _ => return,
+        };
+        self.record(Some(kind));
+    }
+
+    fn record(&self, kind: Option<HealthErrorKind>) {
+        let now = Instant::now();
+        let mut outcomes = self.outcomes.lock().expect("health tracker poisoned");
+        while let Some((at, _)) = outcomes.front() {
+            if now.duration_since(*at) < HEALTH_WINDOW {
+                break;
+            }
+            outcomes.pop_front();
+        }
+        outcomes.push_back((now, kind));
+    }
+
+    pub(crate) fn status(&self) -> ApiStatus {
+        let now = Instant::now();
+        let outcomes = self.outcomes.lock().expect("health tracker poisoned");
+        let mut successes = 0;
+        let mut breakdown: HashMap<HealthErrorKind, usize> = HashMap::new();
+        let mut outage_errors = 0;
+        for (at, kind) in outcomes.iter() {
+            if now.duration_since(*at) >= HEALTH_WINDOW {
+                continue;
+            }
+            match kind {
+                None => successes += 1,
+                Some(kind) => {
+                    *breakdown.entry(*kind).or_default() += 1;
+                    if kind.indicates_outage() {
+                        outage_errors += 1;
+                    }
+                }
+            }
+        }
+        let errors: usize = breakdown.values().sum();
+        let total = successes + errors;
+        let health = if total < MIN_SAMPLES {
+            ApiHealth::Healthy
+        } else {
+            let ratio = outage_errors as f64 / total as f64;
+            if ratio >= DOWN_RATIO {
+                ApiHealth::Down
+            } else if ratio >= DEGRADED_RATIO {
+                ApiHealth::Degraded
+            } else {
+                ApiHealth::Healthy
+            }
+        };
+        ApiStatus {
+            health,
+            successes,
+            errors,
+            breakdown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ApiErrorBody;
+
+    fn api_error(code: u16) -> TornError {
+        TornError::Api(ApiErrorBody {
+            code,
+            message: String::new(),
+        })
+    }
+
+    #[test]
+    fn few_samples_stay_healthy() {
+        let tracker = HealthTracker::default();
+        tracker.record_error(&api_error(codes::BACKEND_ERROR));
+        assert_eq!(tracker.status().health, ApiHealth::Healthy);
+    }
+
+    #[test]
+    fn outage_errors_drive_the_verdict() {
+        let tracker = HealthTracker::default();
+        for _ in 0..5 {
+            tracker.record_success();
+        }
+        for _ in 0..5 {
+            tracker.record_error(&api_error(codes::BACKEND_ERROR));
+        }
+        let status = tracker.status();
+        assert_eq!(status.health, ApiHealth::Down);
+        assert_eq!(status.successes, 5);
+        assert_eq!(
+            status.breakdown[&HealthErrorKind::Api(codes::BACKEND_ERROR)],
+            5
+        );
+    }
+
+    #[test]
+    fn caller_mistakes_do_not_count_as_outage() {
+        let tracker = HealthTracker::default();
+        for _ in 0..10 {
+            tracker.record_error(&api_error(codes::INCORRECT_ID));
+        }
+        let status = tracker.status();
+        assert_eq!(status.health, ApiHealth::Healthy);
+        assert_eq!(status.errors, 10);
+    }
+
+    #[test]
+    fn local_errors_are_not_recorded() {
+        let tracker = HealthTracker::default();
+        tracker.record_error(&TornError::RateLimited { wait: None });
+        let status = tracker.status();
+        assert_eq!(status.successes + status.errors, 0);
+    }
+}
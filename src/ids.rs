@@ -0,0 +1,110 @@
+//! Typed entity IDs with flexible conversions.
+//!
+//! Discord bots and web frontends receive IDs as strings; these newtypes let
+//! every ID-scoped endpoint accept `u64`, `i64`, numeric strings and the
+//! typed IDs interchangeably. Parse failures are carried inside the ID and
+//! surface as [`crate::TornError::InvalidParams`] when the request is made,
+//! so call sites stay free of scattered `.parse()` plumbing.
+
+use crate::{Result, TornError};
+
+macro_rules! entity_id {
+    ($(#[$doc:meta])* $name:ident, $what:literal) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct $name {
+            value: std::result::Result<u64, String>,
+        }
+
+        impl $name {
+            /// An ID known to be valid.
+            pub fn new(value: u64) -> Self {
+                Self { value: Ok(value) }
+            }
+
+            /// Resolves the ID, surfacing any deferred parse failure.
+            pub(crate) fn get(&self) -> Result<u64> {
+                self.value.clone().map_err(|raw| {
+                    TornError::InvalidParams(format!(
+                        "invalid {}: {raw:?} is not a valid id",
+                        $what
+                    ))
+                })
+            }
+        }
+
+        impl From<u64> for $name {
+            fn from(value: u64) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl From<u32> for $name {
+            fn from(value: u32) -> Self {
+                Self::new(value.into())
+            }
+        }
+
+        impl From<i64> for $name {
+            fn from(value: i64) -> Self {
+                Self {
+                    value: u64::try_from(value).map_err(|_| value.to_string()),
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                Self {
+                    value: value
+                        .trim()
+                        .parse::<u64>()
+                        .map_err(|_| value.to_owned()),
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                Self::from(value.as_str())
+            }
+        }
+    };
+}
+
+entity_id!(
+    /// A player ID.
+    UserId,
+    "user id"
+);
+entity_id!(
+    /// A faction ID.
+    FactionId,
+    "faction id"
+);
+entity_id!(
+    /// An item ID.
+    ItemId,
+    "item id"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numeric_sources_convert_cleanly() {
+        assert_eq!(UserId::from(42u64).get().unwrap(), 42);
+        assert_eq!(UserId::from(42u32).get().unwrap(), 42);
+        assert_eq!(UserId::from(42i64).get().unwrap(), 42);
+        assert_eq!(UserId::from(" 42 ").get().unwrap(), 42);
+        assert_eq!(UserId::from("42".to_owned()).get().unwrap(), 42);
+    }
+
+    #[test]
+    fn bad_sources_defer_an_invalid_params_error() {
+        let err = UserId::from("Duke[4]").get().unwrap_err();
+        assert!(matches!(err, TornError::InvalidParams(_)));
+        assert!(UserId::from(-1i64).get().is_err());
+    }
+}
@@ -0,0 +1,380 @@
+//! API key pool and rotation.
+//!
+//! Heavy consumers spread load over several keys; the pool hands out keys
+//! round-robin so each key's 100/minute budget is used evenly. The set of
+//! keys can be swapped at runtime (see key-file hot-reload on the client)
+//! without disturbing in-flight requests.
+
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::RwLock;
+
+use zeroize::Zeroize;
+
+/// Key storage that zeroizes its contents on drop and never shows keys
+/// un-redacted in `Debug` output. Derefs to the inner `Vec<String>`, so call
+/// sites read like a plain key list; the wrapper only changes what happens
+/// on `Drop` and in diagnostics.
+#[derive(Clone, Default)]
+pub(crate) struct SecretKeys(Vec<String>);
+
+impl Drop for SecretKeys {
+    fn drop(&mut self) {
+        for key in &mut self.0 {
+            key.zeroize();
+        }
+    }
+}
+
+impl std::fmt::Debug for SecretKeys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|key| crate::client::redact_key(key)))
+            .finish()
+    }
+}
+
+impl Deref for SecretKeys {
+    type Target = Vec<String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for SecretKeys {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Vec<String>> for SecretKeys {
+    fn from(keys: Vec<String>) -> Self {
+        Self(keys)
+    }
+}
+
+/// How the client picks a pool key for each request.
+///
+/// Configured via [`crate::TornClientConfig::key_balancing`]; only applies
+/// when the pool holds more than one key and the request is not pinned to a
+/// specific key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ApiKeyBalancing {
+    /// Strict rotation order: each key serves every Nth request.
+    #[default]
+    RoundRobin,
+    /// A uniformly random key per request; statistically even without any
+    /// shared cursor, which matters when several clients share one pool.
+    Random,
+    /// The key with the fewest requests in the current window. Maximizes
+    /// throughput when keys arrive with uneven prior usage — e.g. donated
+    /// keys whose owners also use them elsewhere.
+    LeastUsed,
+    /// The key with the most remaining quota, which unlike [`LeastUsed`]
+    /// also steers around cold (penalized) keys and reserved headroom.
+    ///
+    /// [`LeastUsed`]: ApiKeyBalancing::LeastUsed
+    LeastLoaded,
+}
+
+impl std::str::FromStr for ApiKeyBalancing {
+    type Err = crate::TornError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "round-robin" => Ok(Self::RoundRobin),
+            "random" => Ok(Self::Random),
+            "least-used" => Ok(Self::LeastUsed),
+            "least-loaded" => Ok(Self::LeastLoaded),
+            other => Err(crate::TornError::InvalidParams(format!(
+                "unknown key balancing strategy: {other}"
+            ))),
+        }
+    }
+}
+
+/// A pool of one or more Torn API keys.
+pub struct ApiKeyPool {
+    keys: RwLock<SecretKeys>,
+    cursor: AtomicUsize,
+    quarantined: RwLock<SecretKeys>,
+    labels: RwLock<HashMap<String, String>>,
+}
+
+impl std::fmt::Debug for ApiKeyPool {
+    // Manual so the label map's raw keys stay out of diagnostics; the key
+    // lists redact themselves.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let labels: HashMap<String, String> = self
+            .labels
+            .read()
+            .expect("key pool lock poisoned")
+            .iter()
+            .map(|(key, label)| (crate::client::redact_key(key), label.clone()))
+            .collect();
+        f.debug_struct("ApiKeyPool")
+            .field("keys", &*self.keys.read().expect("key pool lock poisoned"))
+            .field(
+                "quarantined",
+                &*self.quarantined.read().expect("key pool lock poisoned"),
+            )
+            .field("labels", &labels)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Drops empty and duplicate entries, preserving first-seen order.
+fn dedup<I, S>(keys: I) -> Vec<String>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let mut seen = Vec::new();
+    for key in keys {
+        let key = key.into();
+        if !key.is_empty() && !seen.contains(&key) {
+            seen.push(key);
+        }
+    }
+    seen
+}
+
+impl ApiKeyPool {
+    /// Builds a pool from the given keys. Empty or duplicate keys are dropped.
+    pub fn new<I, S>(keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            keys: RwLock::new(SecretKeys::from(dedup(keys))),
+            cursor: AtomicUsize::new(0),
+            quarantined: RwLock::new(SecretKeys::default()),
+            labels: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Attaches an operator-facing label to `key` (an owner's name,
+    /// usually). Labels survive quarantine — they describe the credential,
+    /// not its health.
+    pub fn set_label(&self, key: impl Into<String>, label: impl Into<String>) {
+        self.labels
+            .write()
+            .expect("key pool lock poisoned")
+            .insert(key.into(), label.into());
+    }
+
+    /// The label attached to `key`, if any.
+    pub fn label(&self, key: &str) -> Option<String> {
+        self.labels
+            .read()
+            .expect("key pool lock poisoned")
+            .get(key)
+            .cloned()
+    }
+
+    /// Number of keys in the pool.
+    pub fn len(&self) -> usize {
+        self.keys.read().expect("key pool lock poisoned").len()
+    }
+
+    /// Whether the pool holds no keys at all.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Snapshot of the current keys, in rotation order.
+    pub fn keys(&self) -> Vec<String> {
+        self.keys.read().expect("key pool lock poisoned").to_vec()
+    }
+
+    /// Replaces the whole key set. Rotation continues from the current cursor
+    /// position, so in-flight callers are unaffected.
+    pub fn set_keys<I, S>(&self, keys: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        *self.keys.write().expect("key pool lock poisoned") = SecretKeys::from(dedup(keys));
+    }
+
+    /// Adds one key to the rotation. Returns `false` (leaving the pool
+    /// unchanged) when the key is empty or already present. Re-adding a
+    /// quarantined key lifts its quarantine — the operator presumably knows
+    /// it works again.
+    pub fn add_key(&self, key: impl Into<String>) -> bool {
+        let key = key.into();
+        if key.is_empty() {
+            return false;
+        }
+        let mut keys = self.keys.write().expect("key pool lock poisoned");
+        if keys.contains(&key) {
+            return false;
+        }
+        self.quarantined
+            .write()
+            .expect("key pool lock poisoned")
+            .retain(|q| q != &key);
+        keys.push(key);
+        true
+    }
+
+    /// Pulls `key` out of the rotation after the server rejected it as
+    /// invalid, so one dead key cannot keep poisoning a fraction of all
+    /// requests. Returns `false` when the key was not in the rotation (e.g.
+    /// already quarantined by a concurrent request).
+    pub fn quarantine(&self, key: &str) -> bool {
+        let mut keys = self.keys.write().expect("key pool lock poisoned");
+        let before = keys.len();
+        keys.retain(|k| k != key);
+        if keys.len() == before {
+            return false;
+        }
+        self.quarantined
+            .write()
+            .expect("key pool lock poisoned")
+            .push(key.to_owned());
+        true
+    }
+
+    /// Keys currently quarantined, in the order they were pulled.
+    pub fn quarantined(&self) -> Vec<String> {
+        self.quarantined
+            .read()
+            .expect("key pool lock poisoned")
+            .to_vec()
+    }
+
+    /// Removes every key starting with `prefix`, returning the removed
+    /// keys. Matching by prefix lets callers act on the redacted forms that
+    /// appear in logs and usage reports; an empty prefix removes nothing
+    /// rather than everything.
+    pub fn remove_keys(&self, prefix: &str) -> Vec<String> {
+        if prefix.is_empty() {
+            return Vec::new();
+        }
+        let mut keys = self.keys.write().expect("key pool lock poisoned");
+        let mut removed = Vec::new();
+        keys.retain(|key| {
+            if key.starts_with(prefix) {
+                removed.push(key.clone());
+                false
+            } else {
+                true
+            }
+        });
+        let mut labels = self.labels.write().expect("key pool lock poisoned");
+        for key in &removed {
+            labels.remove(key);
+        }
+        removed
+    }
+
+    /// Returns the next key in round-robin order, or `None` if the pool is empty.
+    pub fn next_key(&self) -> Option<String> {
+        let keys = self.keys.read().expect("key pool lock poisoned");
+        if keys.is_empty() {
+            return None;
+        }
+        let i = self.cursor.fetch_add(1, Ordering::Relaxed) % keys.len();
+        Some(keys[i].clone())
+    }
+
+    /// Returns a uniformly random key, or `None` if the pool is empty.
+    pub fn random_key(&self) -> Option<String> {
+        use std::collections::hash_map::RandomState;
+        use std::hash::{BuildHasher, Hasher};
+
+        let keys = self.keys.read().expect("key pool lock poisoned");
+        if keys.is_empty() {
+            return None;
+        }
+        // The std hasher's per-instance seed is randomness enough for load
+        // spreading; mixing in the cursor keeps repeat calls from colliding.
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_usize(self.cursor.fetch_add(1, Ordering::Relaxed));
+        Some(keys[hasher.finish() as usize % keys.len()].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_robin_cycles_through_keys() {
+        let pool = ApiKeyPool::new(["a", "b", "c"]);
+        assert_eq!(pool.next_key().as_deref(), Some("a"));
+        assert_eq!(pool.next_key().as_deref(), Some("b"));
+        assert_eq!(pool.next_key().as_deref(), Some("c"));
+        assert_eq!(pool.next_key().as_deref(), Some("a"));
+    }
+
+    #[test]
+    fn empty_and_duplicate_keys_are_dropped() {
+        let pool = ApiKeyPool::new(["a", "", "a", "b"]);
+        assert_eq!(pool.len(), 2);
+        assert!(ApiKeyPool::new(Vec::<String>::new()).next_key().is_none());
+    }
+
+    #[test]
+    fn keys_can_be_added_and_removed_at_runtime() {
+        let pool = ApiKeyPool::new(["alpha", "beta"]);
+        assert!(pool.add_key("gamma"));
+        assert!(!pool.add_key("gamma"));
+        assert!(!pool.add_key(""));
+        assert_eq!(pool.len(), 3);
+
+        assert_eq!(pool.remove_keys("ga"), vec!["gamma".to_owned()]);
+        assert_eq!(pool.remove_keys("nope"), Vec::<String>::new());
+        assert_eq!(pool.remove_keys(""), Vec::<String>::new());
+        assert_eq!(pool.keys(), vec!["alpha".to_owned(), "beta".to_owned()]);
+    }
+
+    #[test]
+    fn quarantine_pulls_a_key_until_it_is_re_added() {
+        let pool = ApiKeyPool::new(["good", "bad"]);
+        assert!(pool.quarantine("bad"));
+        assert!(!pool.quarantine("bad"));
+        assert_eq!(pool.keys(), vec!["good".to_owned()]);
+        assert_eq!(pool.quarantined(), vec!["bad".to_owned()]);
+
+        assert_eq!(pool.next_key().as_deref(), Some("good"));
+        assert_eq!(pool.next_key().as_deref(), Some("good"));
+
+        assert!(pool.add_key("bad"));
+        assert!(pool.quarantined().is_empty());
+        assert_eq!(pool.len(), 2);
+    }
+
+    #[test]
+    fn random_key_only_hands_out_pool_members() {
+        let pool = ApiKeyPool::new(["a", "b", "c"]);
+        for _ in 0..64 {
+            let key = pool.random_key().unwrap();
+            assert!(pool.keys().contains(&key));
+        }
+        assert!(ApiKeyPool::new(Vec::<String>::new()).random_key().is_none());
+    }
+
+    #[test]
+    fn debug_output_never_shows_raw_keys() {
+        let pool = ApiKeyPool::new(["supersecretkey123", "otherkey456"]);
+        pool.set_label("supersecretkey123", "alice");
+        pool.quarantine("otherkey456");
+        let rendered = format!("{pool:?}");
+        assert!(!rendered.contains("supersecretkey123"), "{rendered}");
+        assert!(!rendered.contains("otherkey456"), "{rendered}");
+        assert!(rendered.contains("alice"), "{rendered}");
+    }
+
+    #[test]
+    fn set_keys_swaps_the_pool_in_place() {
+        let pool = ApiKeyPool::new(["a"]);
+        pool.set_keys(["x", "y"]);
+        assert_eq!(pool.keys(), vec!["x".to_owned(), "y".to_owned()]);
+        assert!(pool.next_key().is_some());
+    }
+}
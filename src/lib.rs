@@ -0,0 +1,93 @@
+//! Rust wrapper for the [Torn City v2 API](https://www.torn.com/swagger.php).
+//!
+//! The entry point is [`TornClient`], which owns the HTTP connection pool,
+//! the API key pool and the per-key rate limiter. Endpoint handles are
+//! obtained from the client and mirror the sections of the official API:
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), torn_client::TornError> {
+//! use torn_client::{TornClient, TornClientConfig};
+//!
+//! let client = TornClient::new(TornClientConfig::new("API_KEY"));
+//! let profile = client.user().profile().await?;
+//! println!("{} [{}]", profile.name, profile.player_id);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Feature flags
+//!
+//! - `rustls-tls` *(default)* / `native-tls` — TLS backend for the HTTP
+//!   client.
+//! - `csv`, `arrow`, `polars`, `sqlite` — optional export and storage
+//!   integrations.
+//! - `config` — load [`TornClientConfig`] from a TOML/JSON file via
+//!   [`TornClientConfig::from_file`].
+//! - `blocking` — synchronous client at `torn_client::blocking` for
+//!   consumers without an async runtime (native targets only).
+//! - `wasm` — required when targeting `wasm32-unknown-unknown`; swaps the
+//!   tokio timer sleeps for `gloo-timers` and relies on reqwest's fetch
+//!   backend.
+//! - `compression` — gzip/brotli `Accept-Encoding` on responses; see
+//!   [`TornClientConfig::compression`].
+//! - `redis-rate-limit` — share one rate limit budget across processes;
+//!   see `torn_client::redis_limit`.
+//!
+//! Building with `default-features = false` and none of the above gives the
+//! minimal dependency tree: the core client with no TLS provider and no
+//! integrations, for constrained environments that bring their own backend.
+
+pub mod backoff;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub mod blocking;
+pub mod budget;
+pub mod catalog;
+pub mod client;
+mod compat;
+#[cfg(feature = "config")]
+mod config_file;
+pub mod domain;
+pub mod endpoints;
+pub mod error;
+pub mod export;
+pub mod health;
+pub mod ids;
+pub mod keys;
+pub mod middleware;
+pub mod models;
+pub mod money;
+pub mod pagination;
+pub mod rate_limit;
+#[cfg(feature = "redis-rate-limit")]
+pub mod redis_limit;
+pub mod storage;
+pub mod tct;
+pub mod usage;
+pub mod v1;
+#[cfg(feature = "validate-responses")]
+mod validate;
+
+pub use backoff::{Backoff, ExponentialBackoff, LinearBackoff};
+pub use budget::BudgetGuard;
+pub use client::{
+    KeyInvalidatedEvent, KeyValidation, KeyValidationReport, LogRedaction, RequestOptions,
+    ResponseEnvelope, ResponseMeta, StaticData, ThrottleEvent, TornClient, TornClientConfig,
+    DEFAULT_USER_AGENT,
+};
+pub use error::TornError;
+pub use health::{ApiHealth, ApiStatus};
+pub use ids::{FactionId, ItemId, UserId};
+pub use keys::ApiKeyBalancing;
+pub use middleware::{Middleware, RequestParts, ResponseParts};
+pub use money::Money;
+pub use pagination::{
+    CollectedPages, ItemStream, PageCursor, PageProgress, PageStream, PaginatedResponse,
+};
+pub use rate_limit::{
+    AcquireContext, BudgetReservation, IpRateLimiter, Priority, RateLimit, RateLimitInfo,
+    RateLimitMode, RateLimiterSnapshot,
+};
+pub use usage::{KeyStats, UsageReport};
+
+/// Convenience alias used by every fallible API in this crate.
+pub type Result<T> = std::result::Result<T, TornError>;
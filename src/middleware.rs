@@ -0,0 +1,55 @@
+//! Hooks around every request the client sends.
+//!
+//! Implement [`Middleware`] and register it via
+//! [`crate::TornClientConfig::middleware`] to attach custom headers, audit
+//! outgoing traffic or rewrite requests without forking the client. The
+//! chain runs in registration order around every request — one-shot and
+//! paginated alike, retries included.
+
+use std::time::Duration;
+
+/// The mutable parts of an outgoing request, handed to
+/// [`Middleware::before_request`] just before the request is built. The API
+/// key is deliberately absent: auth is attached after the chain runs, so
+/// middleware can neither read nor clobber it.
+#[derive(Debug, Clone)]
+pub struct RequestParts {
+    /// The request URL, without the query pairs below.
+    pub url: String,
+    /// Query parameters to append, default params already merged in.
+    pub query: Vec<(String, String)>,
+    /// Extra headers to send.
+    pub headers: Vec<(String, String)>,
+}
+
+/// A summary of one completed exchange, handed to
+/// [`Middleware::after_response`] once the body has been read.
+#[derive(Debug, Clone)]
+pub struct ResponseParts {
+    /// The URL the request went to, after any `before_request` rewrites.
+    pub url: String,
+    /// The HTTP status code. Torn reports API errors as a 200 with an
+    /// error envelope, so a 200 here does not mean the call succeeded.
+    pub status: u16,
+    /// Time from sending the request to the last body byte.
+    pub elapsed: Duration,
+    /// Response body size in bytes.
+    pub body_len: usize,
+}
+
+/// A request interceptor; see the [module docs](self). Both methods default
+/// to no-ops, so implementors override only the side they care about. The
+/// chain runs on the request path — keep implementations cheap.
+pub trait Middleware: Send + Sync {
+    /// Runs before each request is sent; mutate `parts` to rewrite it.
+    fn before_request(&self, parts: &mut RequestParts) {
+        let _ = parts;
+    }
+
+    /// Runs after each response body has arrived — HTTP errors included,
+    /// since those still carry a response. Transport failures (connect,
+    /// TLS, timeout) skip it; there is nothing to report.
+    fn after_response(&self, parts: &ResponseParts) {
+        let _ = parts;
+    }
+}
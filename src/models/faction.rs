@@ -0,0 +1,67 @@
+//! Models for the `/faction` section.
+
+use serde::{Deserialize, Serialize};
+
+use super::user::{LastAction, UserStatus};
+
+/// A single entry from `/faction/members`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactionMember {
+    pub id: u64,
+    pub name: String,
+    pub level: u32,
+    pub days_in_faction: u32,
+    pub position: String,
+    pub is_revivable: bool,
+    pub last_action: LastAction,
+    pub status: UserStatus,
+}
+
+/// A single entry from `/faction/news`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactionNews {
+    pub id: String,
+    pub timestamp: i64,
+    pub news: String,
+}
+
+/// One ranked hall-of-fame entry inside [`FactionHof`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactionHofRank {
+    pub value: i64,
+    pub rank: u32,
+}
+
+/// Response of `/faction/hof`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactionHof {
+    pub rank: FactionHofRank,
+    pub respect: FactionHofRank,
+    pub chain: FactionHofRank,
+}
+
+/// A single entry from `/faction/positions` (abridged to common permissions).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactionPosition {
+    pub name: String,
+    pub default: bool,
+    pub can_use_medical_item: bool,
+    pub can_use_drug_item: bool,
+    pub can_use_energy_refill: bool,
+    pub can_give_money: bool,
+    pub can_give_item: bool,
+    pub can_kick_members: bool,
+    pub can_access_fac_api: bool,
+}
+
+/// Response of `/faction/basic` (abridged).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactionBasic {
+    pub id: u64,
+    pub name: String,
+    pub tag: String,
+    pub leader_id: u64,
+    pub respect: u64,
+    pub members: u32,
+    pub capacity: u32,
+}
@@ -0,0 +1,71 @@
+//! Models for the `/key` section.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// The four access levels a Torn API key can have.
+///
+/// Ordered, so `level >= AccessLevel::Limited` expresses "at least limited".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AccessLevel {
+    Public,
+    Minimal,
+    Limited,
+    Full,
+}
+
+impl AccessLevel {
+    /// The numeric level as reported by `/key/info` (1 through 4).
+    pub fn as_number(self) -> u8 {
+        match self {
+            AccessLevel::Public => 1,
+            AccessLevel::Minimal => 2,
+            AccessLevel::Limited => 3,
+            AccessLevel::Full => 4,
+        }
+    }
+}
+
+impl TryFrom<u8> for AccessLevel {
+    type Error = u8;
+
+    fn try_from(level: u8) -> Result<Self, Self::Error> {
+        match level {
+            1 => Ok(AccessLevel::Public),
+            2 => Ok(AccessLevel::Minimal),
+            3 => Ok(AccessLevel::Limited),
+            4 => Ok(AccessLevel::Full),
+            other => Err(other),
+        }
+    }
+}
+
+impl std::fmt::Display for AccessLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            AccessLevel::Public => "Public",
+            AccessLevel::Minimal => "Minimal",
+            AccessLevel::Limited => "Limited",
+            AccessLevel::Full => "Full",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Access summary embedded in `/key/info`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyAccess {
+    pub level: u8,
+    #[serde(rename = "type")]
+    pub access_type: String,
+}
+
+/// Response of `/key/info`: the key's access level plus the selections it can
+/// request, grouped by API section.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyInfo {
+    pub access: KeyAccess,
+    #[serde(default)]
+    pub selections: HashMap<String, Vec<String>>,
+}
@@ -0,0 +1,28 @@
+//! Models for the `/market` section.
+
+use serde::{Deserialize, Serialize};
+
+/// A single listing inside an [`ItemMarket`] response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemMarketListing {
+    pub id: u64,
+    pub price: i64,
+    pub amount: u32,
+}
+
+/// Item header embedded in `/market/{id}/itemmarket` responses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemMarketItem {
+    pub id: u64,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub item_type: String,
+    pub average_price: i64,
+}
+
+/// Response of `/market/{id}/itemmarket`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemMarket {
+    pub item: ItemMarketItem,
+    pub listings: Vec<ItemMarketListing>,
+}
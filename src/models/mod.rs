@@ -0,0 +1,15 @@
+//! Response models, following the shapes in the Torn v2 OpenAPI specification.
+
+pub mod faction;
+pub mod key;
+pub mod market;
+pub mod racing;
+pub mod torn;
+pub mod user;
+
+pub use faction::{FactionHof, FactionMember, FactionNews, FactionPosition};
+pub use key::{AccessLevel, KeyInfo};
+pub use market::{ItemMarket, ItemMarketListing};
+pub use racing::Race;
+pub use torn::{Item, LogCategory, LogType};
+pub use user::{Attack, Revive, UserEvent, UserProfile};
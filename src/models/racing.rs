@@ -0,0 +1,32 @@
+//! Models for the `/racing` section.
+
+use serde::{Deserialize, Serialize};
+
+/// A single entry from `/racing/races`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Race {
+    pub id: u64,
+    pub title: String,
+    pub track_id: u32,
+    pub status: String,
+    pub laps: u32,
+    pub participants: RaceParticipants,
+    pub schedule: RaceSchedule,
+}
+
+/// Participant counts for a race.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RaceParticipants {
+    pub current: u32,
+    pub maximum: u32,
+    pub minimum: u32,
+}
+
+/// Timing information for a race.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RaceSchedule {
+    pub join_from: i64,
+    pub join_until: i64,
+    pub start: i64,
+    pub end: i64,
+}
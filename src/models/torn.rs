@@ -0,0 +1,108 @@
+//! Models for the `/torn` section.
+
+use serde::{Deserialize, Serialize};
+
+/// Pricing block embedded in item responses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemValue {
+    pub buy_price: Option<i64>,
+    pub sell_price: Option<i64>,
+    pub market_price: i64,
+}
+
+/// A single entry from `/torn/logcategories`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogCategory {
+    pub id: u32,
+    pub title: String,
+}
+
+/// A single entry from `/torn/logtypes`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogType {
+    pub id: u32,
+    pub title: String,
+}
+
+/// A single entry from `/torn/rackets`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Racket {
+    pub name: String,
+    pub level: u32,
+    pub reward: String,
+    pub created_at: i64,
+    pub changed_at: i64,
+    /// ID of the territory hosting this racket, e.g. `"NSC"`.
+    pub territory: String,
+    /// Controlling faction, when one holds the territory.
+    pub faction: Option<u64>,
+}
+
+/// A single entry from `/torn/territory`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Territory {
+    pub id: String,
+    pub sector: u32,
+    pub size: u32,
+    pub density: u32,
+    pub slots: u32,
+    pub daily_respect: u32,
+    /// Owning faction, if claimed.
+    pub faction: Option<u64>,
+    pub coordinate_x: f64,
+    pub coordinate_y: f64,
+}
+
+/// A racket joined with the territory hosting it; see
+/// [`crate::endpoints::FactionEndpoint::rackets_with_territories`].
+#[derive(Debug, Clone)]
+pub struct RacketTerritory {
+    pub racket: Racket,
+    /// The hosting territory, when present in the ownership map.
+    pub territory: Option<Territory>,
+}
+
+/// A single entry from `/torn/crimes`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TornCrime {
+    pub id: u32,
+    pub name: String,
+    pub category_id: u32,
+    pub enhancer_id: Option<u64>,
+}
+
+/// A single entry from `/torn/honors`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Honor {
+    pub id: u32,
+    pub name: String,
+    pub description: String,
+    #[serde(rename = "type")]
+    pub honor_type: u32,
+    pub circulation: u64,
+    pub rarity: String,
+}
+
+/// A single entry from `/torn/medals`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Medal {
+    pub id: u32,
+    pub name: String,
+    pub description: String,
+    #[serde(rename = "type")]
+    pub medal_type: String,
+    pub circulation: u64,
+    pub rarity: String,
+}
+
+/// A single entry from `/torn/items`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Item {
+    pub id: u64,
+    pub name: String,
+    pub description: String,
+    #[serde(rename = "type")]
+    pub item_type: String,
+    pub circulation: u64,
+    pub value: ItemValue,
+}
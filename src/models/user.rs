@@ -0,0 +1,106 @@
+//! Models for the `/user` section.
+
+use serde::{Deserialize, Serialize};
+
+/// One side of an attack (attacker or defender).
+///
+/// Stealthed attackers are reported with all fields absent, which the API
+/// encodes as `null`s inside the participant object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttackParticipant {
+    pub id: Option<u64>,
+    pub name: Option<String>,
+    pub level: Option<u32>,
+    pub faction: Option<AttackFaction>,
+}
+
+/// Faction reference embedded in attack participants.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttackFaction {
+    pub id: u64,
+    pub name: String,
+}
+
+/// Fair-fight and other respect modifiers applied to an attack.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttackModifiers {
+    pub fair_fight: f64,
+    pub war: f64,
+    pub retaliation: f64,
+    pub group: f64,
+    pub overseas: f64,
+    pub chain: f64,
+}
+
+/// A single entry from `/user/attacks` or `/faction/attacks`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attack {
+    pub id: u64,
+    pub code: String,
+    pub started: i64,
+    pub ended: i64,
+    pub attacker: Option<AttackParticipant>,
+    pub defender: AttackParticipant,
+    pub result: String,
+    pub respect_gain: f64,
+    pub respect_loss: f64,
+    pub chain: u32,
+    pub is_stealthed: bool,
+    pub is_raid: bool,
+    pub is_ranked_war: bool,
+    pub modifiers: Option<AttackModifiers>,
+}
+
+/// Participant reference in a revive record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviveParticipant {
+    pub id: u64,
+    pub name: Option<String>,
+    pub faction: Option<AttackFaction>,
+}
+
+/// A single entry from `/user/revives` or `/faction/revives`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Revive {
+    pub id: u64,
+    pub reviver: ReviveParticipant,
+    pub target: ReviveParticipant,
+    pub success_chance: f64,
+    pub result: String,
+    pub timestamp: i64,
+}
+
+/// A single entry from `/user/events`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserEvent {
+    pub id: String,
+    pub timestamp: i64,
+    pub event: String,
+}
+
+/// Last-action summary embedded in profile responses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LastAction {
+    pub status: String,
+    pub timestamp: i64,
+    pub relative: String,
+}
+
+/// Status block embedded in profile and member responses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserStatus {
+    pub description: String,
+    pub state: String,
+    pub until: Option<i64>,
+}
+
+/// Response of `/user/profile` (abridged to the commonly used fields).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserProfile {
+    pub player_id: u64,
+    pub name: String,
+    pub level: u32,
+    pub gender: String,
+    pub last_action: LastAction,
+    pub status: UserStatus,
+}
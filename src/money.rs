@@ -0,0 +1,207 @@
+//! Torn money values.
+//!
+//! The API reports money as large plain integers. [`Money`] wraps an `i64`
+//! with checked arithmetic, the in-game display format (`$1,234,567`) and
+//! parsing of both that format and the shorthand players actually type
+//! (`1.5m`, `800k`, `2b`).
+
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// An amount of Torn cash, in whole dollars.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Money(pub i64);
+
+impl Money {
+    pub const ZERO: Money = Money(0);
+
+    /// The raw dollar amount.
+    pub fn amount(self) -> i64 {
+        self.0
+    }
+
+    /// Checked addition; `None` on overflow.
+    pub fn checked_add(self, other: Money) -> Option<Money> {
+        self.0.checked_add(other.0).map(Money)
+    }
+
+    /// Checked subtraction; `None` on overflow.
+    pub fn checked_sub(self, other: Money) -> Option<Money> {
+        self.0.checked_sub(other.0).map(Money)
+    }
+
+    /// Checked multiplication by a count; `None` on overflow.
+    pub fn checked_mul(self, count: i64) -> Option<Money> {
+        self.0.checked_mul(count).map(Money)
+    }
+
+    /// Saturating addition, pinned to `i64::MIN`/`i64::MAX`.
+    pub fn saturating_add(self, other: Money) -> Money {
+        Money(self.0.saturating_add(other.0))
+    }
+
+    /// Saturating subtraction, pinned to `i64::MIN`/`i64::MAX`.
+    pub fn saturating_sub(self, other: Money) -> Money {
+        Money(self.0.saturating_sub(other.0))
+    }
+}
+
+impl From<i64> for Money {
+    fn from(amount: i64) -> Self {
+        Money(amount)
+    }
+}
+
+impl From<Money> for i64 {
+    fn from(money: Money) -> Self {
+        money.0
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+    fn add(self, other: Money) -> Money {
+        Money(self.0 + other.0)
+    }
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, other: Money) {
+        self.0 += other.0;
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+    fn sub(self, other: Money) -> Money {
+        Money(self.0 - other.0)
+    }
+}
+
+impl SubAssign for Money {
+    fn sub_assign(&mut self, other: Money) {
+        self.0 -= other.0;
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+    fn neg(self) -> Money {
+        Money(-self.0)
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        iter.fold(Money::ZERO, Money::add)
+    }
+}
+
+impl fmt::Display for Money {
+    /// Formats as the game does: `$1,234,567`, with the sign ahead of the `$`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 < 0 {
+            write!(f, "-")?;
+        }
+        let digits = self.0.unsigned_abs().to_string();
+        write!(f, "$")?;
+        let first_group = digits.len() % 3;
+        if first_group != 0 {
+            write!(f, "{}", &digits[..first_group])?;
+            if digits.len() > first_group {
+                write!(f, ",")?;
+            }
+        }
+        let mut groups = digits.as_bytes()[first_group..].chunks(3).peekable();
+        while let Some(group) = groups.next() {
+            write!(f, "{}", std::str::from_utf8(group).expect("ascii digits"))?;
+            if groups.peek().is_some() {
+                write!(f, ",")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error returned when a money string cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid money value: {0:?}")]
+pub struct ParseMoneyError(pub String);
+
+impl FromStr for Money {
+    type Err = ParseMoneyError;
+
+    /// Accepts `$1,234,567`, plain integers, and `k`/`m`/`b` shorthand
+    /// (`800k`, `1.5m`, `2b`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseMoneyError(s.to_owned());
+        let mut rest = s.trim();
+        let negative = rest.starts_with('-');
+        rest = rest.trim_start_matches('-').trim_start_matches('$');
+        let cleaned: String = rest.chars().filter(|c| *c != ',').collect();
+        if cleaned.is_empty() {
+            return Err(err());
+        }
+        let (number, multiplier) = match cleaned.chars().next_back().map(|c| c.to_ascii_lowercase())
+        {
+            Some('k') => (&cleaned[..cleaned.len() - 1], 1_000f64),
+            Some('m') => (&cleaned[..cleaned.len() - 1], 1_000_000f64),
+            Some('b') => (&cleaned[..cleaned.len() - 1], 1_000_000_000f64),
+            _ => (cleaned.as_str(), 1f64),
+        };
+        let value: f64 = number.parse().map_err(|_| err())?;
+        let amount = (value * multiplier).round();
+        if !amount.is_finite() || amount.abs() > i64::MAX as f64 {
+            return Err(err());
+        }
+        let amount = amount as i64;
+        Ok(Money(if negative { -amount } else { amount }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_inserts_thousands_separators() {
+        assert_eq!(Money(0).to_string(), "$0");
+        assert_eq!(Money(999).to_string(), "$999");
+        assert_eq!(Money(1_000).to_string(), "$1,000");
+        assert_eq!(Money(1_234_567).to_string(), "$1,234,567");
+        assert_eq!(Money(-45_000).to_string(), "-$45,000");
+    }
+
+    #[test]
+    fn parses_game_format_and_shorthand() {
+        assert_eq!("$1,234,567".parse::<Money>().unwrap(), Money(1_234_567));
+        assert_eq!("1234567".parse::<Money>().unwrap(), Money(1_234_567));
+        assert_eq!("800k".parse::<Money>().unwrap(), Money(800_000));
+        assert_eq!("1.5m".parse::<Money>().unwrap(), Money(1_500_000));
+        assert_eq!("2B".parse::<Money>().unwrap(), Money(2_000_000_000));
+        assert_eq!("-$500".parse::<Money>().unwrap(), Money(-500));
+        assert!("".parse::<Money>().is_err());
+        assert!("abc".parse::<Money>().is_err());
+    }
+
+    #[test]
+    fn checked_arithmetic_catches_overflow() {
+        assert_eq!(Money(1).checked_add(Money(2)), Some(Money(3)));
+        assert_eq!(Money(i64::MAX).checked_add(Money(1)), None);
+        assert_eq!(Money(i64::MIN).checked_sub(Money(1)), None);
+        assert_eq!(Money(i64::MAX).saturating_add(Money(1)), Money(i64::MAX));
+    }
+
+    #[test]
+    fn sums_like_a_number() {
+        let total: Money = [Money(100), Money(250), Money(650)].into_iter().sum();
+        assert_eq!(total, Money(1_000));
+    }
+}
@@ -0,0 +1,931 @@
+//! Cursor-style pagination over `_metadata.links`.
+//!
+//! List endpoints on the v2 API return their payload next to a `_metadata`
+//! object carrying absolute `next`/`prev` URLs. [`PaginatedResponse`] keeps
+//! those links alongside the decoded page, and [`PageStream`] walks them.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use futures_core::Stream;
+use futures_util::StreamExt;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::client::TornClient;
+use crate::Result;
+
+/// The `links` object inside `_metadata`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PaginationLinks {
+    pub next: Option<String>,
+    pub prev: Option<String>,
+}
+
+/// The `_metadata` object returned by paginated endpoints.
+///
+/// Beyond the navigation `links`, some endpoints report counts and limits;
+/// those are parsed when present and anything unrecognized is preserved in
+/// [`PaginationMetadata::extra`], so consumers can show totals and compute
+/// page progress without this crate chasing every metadata addition.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PaginationMetadata {
+    #[serde(default)]
+    pub links: PaginationLinks,
+    /// Total matching records across all pages, where the API reports it.
+    #[serde(default)]
+    pub total: Option<u64>,
+    /// Records on this page, where the API reports it.
+    #[serde(default)]
+    pub count: Option<u64>,
+    /// Page size limit in effect, where the API reports it.
+    #[serde(default)]
+    pub limit: Option<u64>,
+    /// Offset of this page, where the API reports it.
+    #[serde(default)]
+    pub offset: Option<u64>,
+    /// Any `_metadata` fields this crate does not model.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Wire shape of a paginated response: one list field (whose name varies by
+/// endpoint) plus `_metadata`.
+#[derive(Debug, Deserialize)]
+pub(crate) struct PagedEnvelope<T> {
+    #[serde(rename = "_metadata", default)]
+    pub(crate) metadata: PaginationMetadata,
+    #[serde(flatten)]
+    pub(crate) rest: HashMap<String, Vec<T>>,
+}
+
+impl<T> PagedEnvelope<T> {
+    pub(crate) fn into_data(self) -> (Vec<T>, PaginationMetadata) {
+        let data = self.rest.into_values().next().unwrap_or_default();
+        (data, self.metadata)
+    }
+}
+
+/// A serializable resume point for a paginated walk, wrapping one of the
+/// absolute cursor URLs the API hands out in `_metadata.links`. Store it as
+/// a plain string — it serializes transparently — and pick the walk back up
+/// after a restart with [`crate::TornClient::resume_from`]. Cursor URLs carry no
+/// API key; authentication happens per request.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct PageCursor(String);
+
+impl PageCursor {
+    /// The cursor URL itself.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for PageCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::str::FromStr for PageCursor {
+    type Err = crate::TornError;
+
+    /// Parses a stored cursor back, rejecting strings that are not absolute
+    /// URLs before they turn into a confusing request failure later.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        url::Url::parse(s)
+            .map_err(|e| crate::TornError::InvalidParams(format!("invalid page cursor: {e}")))?;
+        Ok(Self(s.to_owned()))
+    }
+}
+
+/// What [`PaginatedResponse::collect_all`] gathered: the flattened items,
+/// how many pages it took, and where to resume when the cap cut the walk
+/// short.
+#[derive(Debug)]
+pub struct CollectedPages<T> {
+    /// Items from every collected page, in page order.
+    pub items: Vec<T>,
+    /// Pages collected, the first one included; each page beyond the first
+    /// cost one request of key budget.
+    pub pages: usize,
+    /// Cursor of the first uncollected page when `max_pages` stopped the
+    /// walk early, `None` when the API ran out of pages.
+    pub resume_url: Option<String>,
+}
+
+/// Builds the absolute URL of a neighbouring page for an offset/limit
+/// endpoint that reports no `_metadata.links`; see
+/// [`crate::TornClient::get_offset_paged`]. Any `offset`/`limit` already in
+/// `query` is replaced rather than duplicated.
+pub(crate) fn synthesized_offset_link(
+    base_with_path: &str,
+    query: &[(&str, String)],
+    offset: u64,
+    limit: u64,
+) -> String {
+    let Ok(mut url) = url::Url::parse(base_with_path) else {
+        return base_with_path.to_owned();
+    };
+    {
+        let mut pairs = url.query_pairs_mut();
+        for (name, value) in query {
+            if *name != "offset" && *name != "limit" {
+                pairs.append_pair(name, value);
+            }
+        }
+        pairs.append_pair("limit", &limit.to_string());
+        pairs.append_pair("offset", &offset.to_string());
+    }
+    url.to_string()
+}
+
+/// How follow-up pages are fetched and decoded. Captures the wire type —
+/// and any [`PaginatedResponse::map`] applied on top — so mapped pages keep
+/// navigating without their item type being deserializable itself.
+type PageFetcher<T> = Arc<
+    dyn for<'a> Fn(
+            &'a TornClient,
+            &'a str,
+        )
+            -> Pin<Box<dyn Future<Output = Result<PaginatedResponse<T>>> + Send + 'a>>
+        + Send
+        + Sync,
+>;
+
+/// The fetcher for a plain (unmapped) page: decode the wire type directly.
+fn default_fetcher<T: DeserializeOwned + Send + 'static>() -> PageFetcher<T> {
+    Arc::new(|client, url| {
+        Box::pin(async move {
+            let envelope: PagedEnvelope<T> = client.get_url(url, &[]).await?;
+            let (data, metadata) = envelope.into_data();
+            Ok(PaginatedResponse::new(data, metadata, client.clone()))
+        })
+    })
+}
+
+/// The fetcher for a mapped page: fetch through the parent's fetcher, then
+/// convert the items and re-attach this fetcher so the mapping survives
+/// further navigation.
+fn mapped_fetcher<T, U>(
+    fetch: PageFetcher<T>,
+    f: Arc<dyn Fn(T) -> Result<U> + Send + Sync>,
+) -> PageFetcher<U>
+where
+    T: Send + 'static,
+    U: Send + 'static,
+{
+    Arc::new(move |client, url| {
+        let fetch = fetch.clone();
+        let f = f.clone();
+        Box::pin(async move {
+            let page = fetch(client, url).await?;
+            let data = page
+                .data
+                .into_iter()
+                .map(|item| f(item))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(PaginatedResponse {
+                data,
+                metadata: page.metadata,
+                client: page.client,
+                fetch: mapped_fetcher(page.fetch, f.clone()),
+            })
+        })
+    })
+}
+
+/// One decoded page of a list endpoint, with the navigation links needed to
+/// fetch its neighbours.
+pub struct PaginatedResponse<T> {
+    /// The items on this page.
+    pub data: Vec<T>,
+    pub(crate) metadata: PaginationMetadata,
+    // A cheap Arc-backed clone of the client that fetched this page, so
+    // follow-up requests share its connection pool, key rotation and rate
+    // limiter state instead of rebuilding a client per fetch.
+    pub(crate) client: TornClient,
+    fetch: PageFetcher<T>,
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for PaginatedResponse<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PaginatedResponse")
+            .field("data", &self.data)
+            .field("metadata", &self.metadata)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> PaginatedResponse<T> {
+    pub(crate) fn new(data: Vec<T>, metadata: PaginationMetadata, client: TornClient) -> Self
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        Self {
+            data,
+            metadata,
+            client,
+            fetch: default_fetcher::<T>(),
+        }
+    }
+
+    /// Whether the API advertised a following page.
+    pub fn has_next(&self) -> bool {
+        self.metadata.links.next.is_some()
+    }
+
+    /// The cursor URL of the next page, if any. Useful for persisting resume
+    /// points across process runs.
+    pub fn next_url(&self) -> Option<&str> {
+        self.metadata.links.next.as_deref()
+    }
+
+    /// The next page's link as a storable cursor, if any; see
+    /// [`PageCursor`].
+    pub fn next_cursor(&self) -> Option<PageCursor> {
+        self.metadata.links.next.clone().map(PageCursor)
+    }
+
+    /// The previous page's link as a storable cursor, if any.
+    pub fn prev_cursor(&self) -> Option<PageCursor> {
+        self.metadata.links.prev.clone().map(PageCursor)
+    }
+
+    /// The complete `_metadata` object this page arrived with.
+    pub fn metadata(&self) -> &PaginationMetadata {
+        &self.metadata
+    }
+}
+
+impl<T: Send + 'static> PaginatedResponse<T> {
+    /// Fetches the next page, or returns `None` on the last page.
+    pub async fn next_page(&self) -> Result<Option<PaginatedResponse<T>>> {
+        self.follow(self.metadata.links.next.as_deref()).await
+    }
+
+    /// Fetches the previous page, or returns `None` on the first page.
+    pub async fn prev_page(&self) -> Result<Option<PaginatedResponse<T>>> {
+        self.follow(self.metadata.links.prev.as_deref()).await
+    }
+
+    async fn follow(&self, link: Option<&str>) -> Result<Option<PaginatedResponse<T>>> {
+        let Some(url) = link else {
+            return Ok(None);
+        };
+        (self.fetch)(&self.client, url).await.map(Some)
+    }
+
+    /// Converts the items into another type while the page keeps
+    /// navigating: `next_page`/`prev_page` (and every stream adapter built
+    /// on them) fetch the wire type and re-apply `f`, so callers work in
+    /// their own domain types end to end.
+    pub fn map<U: Send + 'static>(
+        self,
+        f: impl Fn(T) -> U + Send + Sync + 'static,
+    ) -> PaginatedResponse<U> {
+        self.try_map(move |item| Ok(f(item)))
+            .expect("infallible map cannot fail")
+    }
+
+    /// Like [`PaginatedResponse::map`] with a fallible conversion; a
+    /// conversion failure on a later page surfaces as that page's fetch
+    /// error.
+    pub fn try_map<U: Send + 'static>(
+        self,
+        f: impl Fn(T) -> Result<U> + Send + Sync + 'static,
+    ) -> Result<PaginatedResponse<U>> {
+        let f: Arc<dyn Fn(T) -> Result<U> + Send + Sync> = Arc::new(f);
+        let data = self
+            .data
+            .into_iter()
+            .map(|item| f(item))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(PaginatedResponse {
+            data,
+            metadata: self.metadata,
+            client: self.client,
+            fetch: mapped_fetcher(self.fetch, f),
+        })
+    }
+
+    /// Collects this page and every following one into a flat `Vec`,
+    /// walking `next` links until the API runs out or `max_pages` pages
+    /// (this one included) have been gathered. The hard cap protects key
+    /// quota against unexpectedly deep histories; when it bites, the
+    /// result carries the cursor to resume from. For a budget shared
+    /// across several walks, see
+    /// [`PaginatedResponse::collect_with_budget`].
+    pub async fn collect_all(self, max_pages: usize) -> Result<CollectedPages<T>> {
+        let mut page = self;
+        let mut items = Vec::new();
+        let mut pages = 0;
+        loop {
+            items.append(&mut page.data);
+            pages += 1;
+            if pages >= max_pages || !page.has_next() {
+                return Ok(CollectedPages {
+                    items,
+                    pages,
+                    resume_url: page.next_url().map(str::to_owned),
+                });
+            }
+            match page.next_page().await? {
+                Some(next) => page = next,
+                None => {
+                    return Ok(CollectedPages {
+                        items,
+                        pages,
+                        resume_url: None,
+                    })
+                }
+            }
+        }
+    }
+
+    /// Collects items from this page and following ones, spending one budget
+    /// slot per page fetched beyond this first one. Stops with a resumable
+    /// cursor instead of exceeding the budget.
+    pub async fn collect_with_budget(
+        self,
+        budget: &crate::budget::BudgetGuard,
+    ) -> Result<crate::budget::BudgetedFetch<T>> {
+        let mut page = self;
+        let mut items = Vec::new();
+        loop {
+            items.append(&mut page.data);
+            if !page.has_next() {
+                return Ok(crate::budget::BudgetedFetch {
+                    items,
+                    resume_url: None,
+                });
+            }
+            if !budget.try_acquire() {
+                return Ok(crate::budget::BudgetedFetch {
+                    items,
+                    resume_url: page.next_url().map(str::to_owned),
+                });
+            }
+            match page.next_page().await? {
+                Some(next) => page = next,
+                None => {
+                    return Ok(crate::budget::BudgetedFetch {
+                        items,
+                        resume_url: None,
+                    })
+                }
+            }
+        }
+    }
+
+    /// Like [`PaginatedResponse::into_stream`], but a transient fetch failure
+    /// (timeout, error 5, temporary backend error) no longer kills the
+    /// stream: the same cursor is retried with delays from `backoff` until
+    /// the policy gives up. Fatal errors still terminate after surfacing, so
+    /// a single 502 cannot abort a long export while a bad key still does.
+    pub fn into_stream_resilient(self, backoff: Arc<dyn crate::backoff::Backoff>) -> PageStream<T>
+    where
+        T: Sync,
+    {
+        let stream = futures_util::stream::unfold(
+            Some(Ok(self)),
+            move |state: Option<Result<PaginatedResponse<T>>>| {
+                let backoff = backoff.clone();
+                async move {
+                    match state? {
+                        Err(e) => Some((Err(e), None)),
+                        Ok(page) => {
+                            let mut attempt = 0u32;
+                            let next = loop {
+                                match page.next_page().await {
+                                    Ok(Some(next)) => break Some(Ok(next)),
+                                    Ok(None) => break None,
+                                    Err(e) => {
+                                        attempt += 1;
+                                        if !e.is_transient() {
+                                            break Some(Err(e));
+                                        }
+                                        match backoff.next_delay(attempt, &e) {
+                                            Some(delay) => crate::compat::sleep(delay).await,
+                                            None => break Some(Err(e)),
+                                        }
+                                    }
+                                }
+                            };
+                            Some((Ok(page), next))
+                        }
+                    }
+                }
+            },
+        );
+        PageStream {
+            inner: stream.boxed(),
+        }
+    }
+
+    /// Flattens this page and every following one straight into a stream of
+    /// their records, so callers iterate items without ever unwrapping
+    /// pages:
+    ///
+    /// ```no_run
+    /// # async fn run(first: torn_client::PaginatedResponse<torn_client::models::user::Attack>)
+    /// # -> Result<(), torn_client::TornError> {
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let mut attacks = first.into_items();
+    /// while let Some(attack) = attacks.try_next().await? {
+    ///     println!("{}", attack.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Shorthand for [`PaginatedResponse::into_stream`] followed by
+    /// [`PageStream::into_items`].
+    pub fn into_items(self) -> ItemStream<T>
+    where
+        T: Sync,
+    {
+        self.into_stream().into_items()
+    }
+
+    /// Like [`PaginatedResponse::pages_rev`] with the per-page retry of
+    /// [`PaginatedResponse::into_stream_resilient`]: transient fetch
+    /// failures on a `prev` link are retried with delays from `backoff`
+    /// instead of terminating the backwards walk.
+    pub fn pages_rev_resilient(self, backoff: Arc<dyn crate::backoff::Backoff>) -> PageStream<T>
+    where
+        T: Sync,
+    {
+        let stream = futures_util::stream::unfold(
+            Some(Ok(self)),
+            move |state: Option<Result<PaginatedResponse<T>>>| {
+                let backoff = backoff.clone();
+                async move {
+                    match state? {
+                        Err(e) => Some((Err(e), None)),
+                        Ok(page) => {
+                            let mut attempt = 0u32;
+                            let prev = loop {
+                                match page.prev_page().await {
+                                    Ok(Some(prev)) => break Some(Ok(prev)),
+                                    Ok(None) => break None,
+                                    Err(e) => {
+                                        attempt += 1;
+                                        if !e.is_transient() {
+                                            break Some(Err(e));
+                                        }
+                                        match backoff.next_delay(attempt, &e) {
+                                            Some(delay) => crate::compat::sleep(delay).await,
+                                            None => break Some(Err(e)),
+                                        }
+                                    }
+                                }
+                            };
+                            Some((Ok(page), prev))
+                        }
+                    }
+                }
+            },
+        );
+        PageStream {
+            inner: stream.boxed(),
+        }
+    }
+
+    /// Turns this page into a stream that yields it and every *preceding*
+    /// page, walking `prev` links instead of `next` — for consumers that
+    /// land mid-dataset (say, resumed from a stored cursor) and need to
+    /// iterate backwards in time. The counterpart of
+    /// [`PaginatedResponse::into_stream`].
+    pub fn pages_rev(self) -> PageStream<T>
+    where
+        T: Sync,
+    {
+        let stream = futures_util::stream::unfold(
+            Some(Ok(self)),
+            |state: Option<Result<PaginatedResponse<T>>>| async move {
+                match state? {
+                    Err(e) => Some((Err(e), None)),
+                    Ok(page) => {
+                        let prev = match page.prev_page().await {
+                            Ok(Some(prev)) => Some(Ok(prev)),
+                            Ok(None) => None,
+                            Err(e) => Some(Err(e)),
+                        };
+                        Some((Ok(page), prev))
+                    }
+                }
+            },
+        );
+        PageStream {
+            inner: stream.boxed(),
+        }
+    }
+
+    /// Turns this page into a stream that yields it and every following page.
+    pub fn into_stream(self) -> PageStream<T>
+    where
+        T: Sync,
+    {
+        let stream = futures_util::stream::unfold(
+            Some(Ok(self)),
+            |state: Option<Result<PaginatedResponse<T>>>| async move {
+                match state? {
+                    Err(e) => Some((Err(e), None)),
+                    Ok(page) => {
+                        let next = match page.next_page().await {
+                            Ok(Some(next)) => Some(Ok(next)),
+                            Ok(None) => None,
+                            Err(e) => Some(Err(e)),
+                        };
+                        Some((Ok(page), next))
+                    }
+                }
+            },
+        );
+        PageStream {
+            inner: stream.boxed(),
+        }
+    }
+}
+
+/// Running totals handed to a [`PageStream::progress`] hook after each
+/// successfully fetched page.
+#[derive(Debug, Clone, Copy)]
+pub struct PageProgress {
+    /// Pages fetched so far, this one included.
+    pub pages: u64,
+    /// Records seen across all pages so far.
+    pub records: u64,
+    /// Whether the API advertised a page after this one.
+    pub has_next: bool,
+}
+
+/// A stream of pages, starting from an initial [`PaginatedResponse`] and
+/// following `next` links until exhausted.
+pub struct PageStream<T> {
+    inner: Pin<Box<dyn Stream<Item = Result<PaginatedResponse<T>>> + Send>>,
+}
+
+impl<T> Stream for PageStream<T> {
+    type Item = Result<PaginatedResponse<T>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+impl<T: Send + 'static> PageStream<T> {
+    /// Runs page fetches ahead of the consumer: a background task keeps up
+    /// to `n` pages buffered, so the next fetch overlaps with processing of
+    /// the current page and a long backfill spends its time in whichever of
+    /// the two is slower instead of their sum. The rate limiter paces the
+    /// background fetches exactly as it would the foreground ones, and the
+    /// task stops as soon as the stream is dropped.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn prefetch(self, n: usize) -> PageStream<T> {
+        let (tx, rx) = tokio::sync::mpsc::channel(n.max(1));
+        let mut inner = self.inner;
+        tokio::spawn(async move {
+            while let Some(page) = inner.next().await {
+                if tx.send(page).await.is_err() {
+                    break;
+                }
+            }
+        });
+        let stream = futures_util::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|page| (page, rx))
+        });
+        PageStream {
+            inner: stream.boxed(),
+        }
+    }
+
+    /// Spaces pages at least `interval` apart, so a long backfill
+    /// deliberately trickles — `Duration::from_secs(6)` is roughly ten
+    /// pages a minute — and leaves quota headroom for latency-sensitive
+    /// traffic on the same key pool, instead of racing the rate limiter to
+    /// the cap. The first page is yielded immediately; each later fetch
+    /// waits out the remainder of its interval first.
+    pub fn paced(self, interval: Duration) -> PageStream<T> {
+        let stream = futures_util::stream::unfold(
+            (self.inner, None::<Instant>),
+            move |(mut inner, last)| async move {
+                if let Some(last) = last {
+                    let elapsed = last.elapsed();
+                    if elapsed < interval {
+                        crate::compat::sleep(interval - elapsed).await;
+                    }
+                }
+                let page = inner.next().await?;
+                Some((page, (inner, Some(Instant::now()))))
+            },
+        );
+        PageStream {
+            inner: stream.boxed(),
+        }
+    }
+
+    /// At most `n` pages; the stream ends there and nothing further is
+    /// fetched.
+    pub fn take_pages(self, n: usize) -> PageStream<T> {
+        PageStream {
+            inner: self.inner.take(n).boxed(),
+        }
+    }
+
+    /// Yields pages until `stop` returns `true` for one's data. That page
+    /// is still yielded — it may straddle the boundary — and nothing after
+    /// it is fetched. Lets an incremental sync halt at already-ingested
+    /// records instead of breaking out of a loop by hand:
+    ///
+    /// ```no_run
+    /// # fn run(pages: torn_client::PageStream<torn_client::models::user::Attack>, cutoff: i64) {
+    /// let new_pages = pages.until(move |data| data.iter().any(|a| a.started < cutoff));
+    /// # }
+    /// ```
+    ///
+    /// Fetch errors pass through without consulting `stop`.
+    pub fn until(self, mut stop: impl FnMut(&[T]) -> bool + Send + 'static) -> PageStream<T> {
+        let stream = self.inner.scan(false, move |done, page| {
+            if *done {
+                return futures_util::future::ready(None);
+            }
+            if let Ok(page) = &page {
+                *done = stop(&page.data);
+            }
+            futures_util::future::ready(Some(page))
+        });
+        PageStream {
+            inner: stream.boxed(),
+        }
+    }
+
+    /// Calls `hook` with running totals after each page, so a CLI can
+    /// render a progress bar over a long backfill without wrapping the
+    /// stream itself. Pages pass through unchanged; fetch errors skip the
+    /// hook. Composes with the other adapters — apply it before
+    /// [`PageStream::into_items`] to keep page-level counts.
+    pub fn progress(self, mut hook: impl FnMut(PageProgress) + Send + 'static) -> PageStream<T> {
+        let mut pages = 0u64;
+        let mut records = 0u64;
+        let stream = self.inner.map(move |page| {
+            if let Ok(page) = &page {
+                pages += 1;
+                records += page.data.len() as u64;
+                hook(PageProgress {
+                    pages,
+                    records,
+                    has_next: page.has_next(),
+                });
+            }
+            page
+        });
+        PageStream {
+            inner: stream.boxed(),
+        }
+    }
+
+    /// Flattens this stream of pages into a stream of their items, so callers
+    /// can iterate records without caring where page boundaries fall. A page
+    /// fetch failure surfaces as one `Err` item in place of that page.
+    pub fn into_items(self) -> ItemStream<T> {
+        let stream = self.flat_map(|page| match page {
+            Ok(page) => futures_util::stream::iter(page.data.into_iter().map(Ok)).left_stream(),
+            Err(e) => futures_util::stream::iter(std::iter::once(Err(e))).right_stream(),
+        });
+        ItemStream {
+            inner: stream.boxed(),
+        }
+    }
+}
+
+/// A stream of individual records flattened across page boundaries; see
+/// [`PageStream::into_items`].
+pub struct ItemStream<T> {
+    inner: Pin<Box<dyn Stream<Item = Result<T>> + Send>>,
+}
+
+impl<T> Stream for ItemStream<T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+impl<T: Send + 'static> ItemStream<T> {
+    /// Drops records already seen earlier in the stream, identified by
+    /// `key`. Torn's cursor pagination can repeat edge records when new
+    /// data lands between two page fetches; this catches those repeats at
+    /// the page seam without the caller keeping their own seen-set. Every
+    /// key is held in memory for the life of the stream, so key something
+    /// small (an ID, not the record). Fetch errors pass through unexamined.
+    pub fn dedup_by_key<K>(self, mut key: impl FnMut(&T) -> K + Send + 'static) -> ItemStream<T>
+    where
+        K: std::hash::Hash + Eq + Send + 'static,
+    {
+        let mut seen = std::collections::HashSet::new();
+        let stream = self.inner.filter(move |item| {
+            let keep = match item {
+                Ok(item) => seen.insert(key(item)),
+                Err(_) => true,
+            };
+            futures_util::future::ready(keep)
+        });
+        ItemStream {
+            inner: stream.boxed(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn envelope_extracts_single_list_field() {
+        let json = r#"{"attacks":[1,2,3],"_metadata":{"links":{"next":"u","prev":null}}}"#;
+        let envelope: PagedEnvelope<u32> = serde_json::from_str(json).unwrap();
+        let (data, metadata) = envelope.into_data();
+        assert_eq!(data, vec![1, 2, 3]);
+        assert_eq!(metadata.links.next.as_deref(), Some("u"));
+    }
+
+    #[test]
+    fn metadata_keeps_counts_and_unknown_fields() {
+        let json = r#"{"attacks":[],"_metadata":{
+            "links":{"next":null,"prev":null},
+            "total":1234,"limit":100,"offset":200,
+            "server_shard":"api-3"
+        }}"#;
+        let envelope: PagedEnvelope<u32> = serde_json::from_str(json).unwrap();
+        let (_, metadata) = envelope.into_data();
+        assert_eq!(metadata.total, Some(1234));
+        assert_eq!(metadata.limit, Some(100));
+        assert_eq!(metadata.offset, Some(200));
+        assert_eq!(metadata.count, None);
+        assert_eq!(
+            metadata.extra["server_shard"],
+            serde_json::Value::from("api-3")
+        );
+    }
+
+    fn numbered_pages(client: &crate::TornClient, count: u32) -> PageStream<u32> {
+        let pages: Vec<Result<PaginatedResponse<u32>>> = (0..count)
+            .map(|n| {
+                Ok(PaginatedResponse::new(
+                    vec![n],
+                    PaginationMetadata::default(),
+                    client.clone(),
+                ))
+            })
+            .collect();
+        PageStream {
+            inner: futures_util::stream::iter(pages).boxed(),
+        }
+    }
+
+    #[tokio::test]
+    async fn take_pages_and_until_stop_the_walk_early() {
+        let client = crate::TornClient::new(crate::TornClientConfig::new("k"));
+
+        let taken: Vec<_> = numbered_pages(&client, 5).take_pages(2).collect().await;
+        assert_eq!(taken.len(), 2);
+
+        let until: Vec<u32> = numbered_pages(&client, 5)
+            .until(|data| data.contains(&3))
+            .map(|page| page.unwrap().data[0])
+            .collect()
+            .await;
+        // The boundary page is included; nothing after it appears.
+        assert_eq!(until, vec![0, 1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn dedup_drops_records_repeated_across_pages() {
+        let client = crate::TornClient::new(crate::TornClientConfig::new("k"));
+        let pages: Vec<Result<PaginatedResponse<u32>>> = [vec![0, 1], vec![1, 2], vec![2, 3]]
+            .into_iter()
+            .map(|data| {
+                Ok(PaginatedResponse::new(
+                    data,
+                    PaginationMetadata::default(),
+                    client.clone(),
+                ))
+            })
+            .collect();
+        let stream = PageStream {
+            inner: futures_util::stream::iter(pages).boxed(),
+        };
+        let items: Vec<u32> = stream
+            .into_items()
+            .dedup_by_key(|n| *n)
+            .map(|item| item.unwrap())
+            .collect()
+            .await;
+        assert_eq!(items, vec![0, 1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn progress_hook_sees_running_totals() {
+        let client = crate::TornClient::new(crate::TornClientConfig::new("k"));
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let collected: Vec<_> = numbered_pages(&client, 3)
+            .progress(move |p| sink.lock().unwrap().push((p.pages, p.records, p.has_next)))
+            .collect()
+            .await;
+        assert_eq!(collected.len(), 3);
+        let seen = seen.lock().unwrap();
+        // One record per page; the fixture pages carry no next link.
+        assert_eq!(*seen, vec![(1, 1, false), (2, 2, false), (3, 3, false)]);
+    }
+
+    #[tokio::test]
+    async fn paced_spreads_pages_over_time() {
+        let client = crate::TornClient::new(crate::TornClientConfig::new("k"));
+        let started = Instant::now();
+        let collected: Vec<_> = numbered_pages(&client, 3)
+            .paced(Duration::from_millis(30))
+            .collect()
+            .await;
+        assert_eq!(collected.len(), 3);
+        // Two inter-page gaps of 30ms each.
+        assert!(started.elapsed() >= Duration::from_millis(60));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn prefetch_preserves_page_order() {
+        let client = crate::TornClient::new(crate::TornClientConfig::new("k"));
+        let collected: Vec<_> = numbered_pages(&client, 5).prefetch(2).collect().await;
+        let first_items: Vec<u32> = collected
+            .into_iter()
+            .map(|page| page.unwrap().data[0])
+            .collect();
+        assert_eq!(first_items, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn map_converts_items_and_keeps_navigation_links() {
+        let client = crate::TornClient::new(crate::TornClientConfig::new("k"));
+        let metadata = PaginationMetadata {
+            links: PaginationLinks {
+                next: Some("u".to_owned()),
+                prev: None,
+            },
+            ..Default::default()
+        };
+        let page = PaginatedResponse::new(vec![1u32, 2, 3], metadata, client.clone())
+            .map(|n| n.to_string());
+        assert_eq!(page.data, ["1", "2", "3"]);
+        assert_eq!(page.next_url(), Some("u"));
+
+        let failed = PaginatedResponse::new(vec![1u32], PaginationMetadata::default(), client)
+            .try_map(|n| -> Result<u32> {
+                Err(crate::TornError::InvalidParams(format!("bad item {n}")))
+            });
+        assert!(failed.is_err());
+    }
+
+    #[test]
+    fn synthesized_offset_links_replace_prior_offsets() {
+        let link = synthesized_offset_link(
+            "https://api.torn.com/v2/key/log",
+            &[("cat", "attack".to_owned()), ("offset", "0".to_owned())],
+            200,
+            100,
+        );
+        assert_eq!(
+            link,
+            "https://api.torn.com/v2/key/log?cat=attack&limit=100&offset=200"
+        );
+    }
+
+    #[test]
+    fn cursors_round_trip_through_strings() {
+        let url = "https://api.torn.com/v2/faction/attacks?limit=100&to=1699999999";
+        let cursor: PageCursor = url.parse().unwrap();
+        assert_eq!(cursor.to_string(), url);
+        assert_eq!(
+            serde_json::from_str::<PageCursor>(&serde_json::to_string(&cursor).unwrap()).unwrap(),
+            cursor
+        );
+        assert!("not a url".parse::<PageCursor>().is_err());
+    }
+
+    #[test]
+    fn envelope_tolerates_missing_metadata() {
+        let envelope: PagedEnvelope<u32> = serde_json::from_str(r#"{"news":[]}"#).unwrap();
+        let (data, metadata) = envelope.into_data();
+        assert!(data.is_empty());
+        assert!(metadata.links.next.is_none());
+    }
+}
@@ -0,0 +1,1112 @@
+//! Local rate limiting.
+//!
+//! Torn allows 100 requests per minute per API key. The client enforces this
+//! locally so that well-behaved tools never trip error code 5 on the server.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::sync::Mutex;
+
+/// Requests allowed per key per window, as documented by Torn.
+pub const REQUESTS_PER_MINUTE: u32 = 100;
+
+const WINDOW: Duration = Duration::from_secs(60);
+
+/// How the client reacts when a key is at its request budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateLimitMode {
+    /// Sleep until a slot frees up, then send the request. The default.
+    #[default]
+    AutoDelay,
+    /// Fail fast with [`crate::TornError::RateLimited`].
+    Error,
+    /// Do no local limiting at all; the server will enforce its own limit.
+    Off,
+}
+
+impl std::str::FromStr for RateLimitMode {
+    type Err = crate::TornError;
+
+    /// Parses the mode from its kebab-case name (`auto-delay`, `error`,
+    /// `off`); `auto_delay` and `autodelay` are accepted as well.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "auto-delay" | "auto_delay" | "autodelay" => Ok(Self::AutoDelay),
+            "error" => Ok(Self::Error),
+            "off" => Ok(Self::Off),
+            other => Err(crate::TornError::InvalidParams(format!(
+                "unknown rate limit mode {other:?} (expected auto-delay, error or off)"
+            ))),
+        }
+    }
+}
+
+/// How urgently a request needs its rate limit slot. When quota is scarce,
+/// waiters are granted slots strictly by priority — an interactive call
+/// tagged [`Priority::High`] jumps ahead of a [`Priority::Background`]
+/// pagination sweep — and in arrival order within one level. Tag individual
+/// calls via [`crate::RequestOptions::priority`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Priority {
+    /// Interactive traffic, served before everything else.
+    High,
+    /// Ordinary requests. The default.
+    #[default]
+    Normal,
+    /// Bulk work that should yield to everything else.
+    Background,
+}
+
+impl Priority {
+    /// Index into per-priority waiter bookkeeping, `High` first.
+    fn rank(self) -> usize {
+        match self {
+            Priority::High => 0,
+            Priority::Normal => 1,
+            Priority::Background => 2,
+        }
+    }
+}
+
+/// How often a waiter deferring to a higher-priority one re-checks whether
+/// the coast is clear.
+const PRIORITY_DEFER_POLL: Duration = Duration::from_millis(50);
+
+/// Per-acquisition context threaded from the client into the limiter: the
+/// waiter's [`Priority`] and, for calls drawing from a
+/// [`BudgetReservation`], the reservation to consume.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AcquireContext {
+    /// How urgently this acquisition needs its slot.
+    pub priority: Priority,
+    /// A [`BudgetReservation::id`] whose earmark this call draws from.
+    pub reservation: Option<u64>,
+}
+
+/// Earmarked request slots, from [`crate::TornClient::reserve_budget`].
+///
+/// While the guard is live, other traffic sees correspondingly reduced
+/// headroom, and calls tagged via [`crate::RequestOptions::reservation`]
+/// draw from the earmark at the full cap — so a long bulk task is not
+/// starved midway by competing traffic. Dropping the guard releases
+/// whatever is left; the time-to-live passed at reservation does the same
+/// if the holder never gets around to it.
+#[derive(Debug)]
+pub struct BudgetReservation {
+    limiter: Arc<dyn RateLimit>,
+    id: u64,
+    amount: u32,
+}
+
+impl BudgetReservation {
+    pub(crate) fn new(limiter: Arc<dyn RateLimit>, id: u64, amount: u32) -> Self {
+        Self {
+            limiter,
+            id,
+            amount,
+        }
+    }
+
+    /// Identifier tagging calls that draw from this earmark.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Slots originally earmarked.
+    pub fn amount(&self) -> u32 {
+        self.amount
+    }
+}
+
+impl Drop for BudgetReservation {
+    fn drop(&mut self) {
+        self.limiter.cancel_reservation(self.id);
+    }
+}
+
+/// Future returned by [`RateLimit::acquire`]; boxed so the trait stays object
+/// safe.
+pub type RateLimitFuture<'a> = Pin<Box<dyn Future<Output = bool> + Send + 'a>>;
+
+/// Future returned by [`RateLimit::status`]; boxed so the trait stays object
+/// safe.
+pub type RateLimitStatusFuture<'a> =
+    Pin<Box<dyn Future<Output = HashMap<String, RateLimitInfo>> + Send + 'a>>;
+
+/// Serializable snapshot of per-key window state, as unix timestamps in
+/// seconds. Lets a restarted process resume mid-window instead of
+/// immediately firing a fresh burst that trips Torn's server-side limit;
+/// see [`crate::TornClient::save_rate_limit_state`] and
+/// [`crate::TornClientConfig::rate_limit_state`]. Like a key file, the
+/// serialized form contains the pool's API keys — store it accordingly.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct RateLimiterSnapshot {
+    /// Recent request times per API key.
+    pub windows: HashMap<String, Vec<i64>>,
+}
+
+/// Point-in-time view of one key's local budget.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// Requests recorded inside the current window.
+    pub used: u32,
+    /// Slots left before the cap (zero while saturated).
+    pub remaining: u32,
+    /// Time left on a saturation penalty, set when the server returned
+    /// error code 5 despite local accounting saying slots remained.
+    pub cold_remaining: Option<Duration>,
+    /// Operator label for the key, filled in by the client from its pool;
+    /// limiters themselves always leave it `None`.
+    pub label: Option<String>,
+}
+
+/// A replaceable per-key rate limiting strategy.
+///
+/// The client consults this before every request. Resolving to `true` sends
+/// the request (possibly after an internal wait); `false` fails it fast with
+/// [`crate::TornError::RateLimited`]. Install a custom implementation —
+/// distributed, weighted, cost-based — via
+/// [`crate::TornClientConfig::rate_limiter`]; the built-in sliding window
+/// honoring Torn's 100/minute cap remains the default.
+pub trait RateLimit: Send + Sync + std::fmt::Debug {
+    /// Acquires a request slot for `key` according to `mode`.
+    fn acquire<'a>(&'a self, key: &'a str, mode: RateLimitMode) -> RateLimitFuture<'a>;
+
+    /// Serializes the current window state, or `None` when this limiter has
+    /// no local state worth persisting (e.g. it already lives in an external
+    /// store).
+    fn snapshot(&self) -> Option<RateLimiterSnapshot> {
+        None
+    }
+
+    /// Restores previously-snapshotted window state. The default does
+    /// nothing.
+    fn restore(&self, _snapshot: RateLimiterSnapshot) {}
+
+    /// Marks `key` as saturated after the server rejected it with error
+    /// code 5 despite local accounting, so subsequent acquisitions treat it
+    /// as cold instead of immediately re-tripping the same limit. The
+    /// default does nothing.
+    fn penalize(&self, _key: &str) {}
+
+    /// Like [`RateLimit::acquire`], reporting each wait the limiter is about
+    /// to sleep through to `on_wait` before sleeping. Returning `false` from
+    /// the observer abandons the acquisition (the request fails with
+    /// [`crate::TornError::RateLimited`]) — the client uses this to fire
+    /// [`crate::TornClientConfig::on_throttle`] and to enforce
+    /// [`crate::TornClientConfig::max_rate_limit_wait`]. The default ignores
+    /// the observer and defers to `acquire`.
+    fn acquire_observed<'a>(
+        &'a self,
+        key: &'a str,
+        mode: RateLimitMode,
+        on_wait: &'a (dyn Fn(Duration) -> bool + Sync),
+    ) -> RateLimitFuture<'a> {
+        let _ = on_wait;
+        self.acquire(key, mode)
+    }
+
+    /// Like [`RateLimit::acquire_observed`] with an [`AcquireContext`]
+    /// attached, so limiters with a waiter queue can grant scarce slots to
+    /// urgent traffic first and honor budget reservations. The default
+    /// ignores the context.
+    fn acquire_with<'a>(
+        &'a self,
+        key: &'a str,
+        mode: RateLimitMode,
+        context: AcquireContext,
+        on_wait: &'a (dyn Fn(Duration) -> bool + Sync),
+    ) -> RateLimitFuture<'a> {
+        let _ = context;
+        self.acquire_observed(key, mode, on_wait)
+    }
+
+    /// Earmarks `amount` request slots for one holder until cancelled or
+    /// `ttl` elapses, returning an identifier for [`AcquireContext`]
+    /// tagging. `None` when this limiter does not support reservations (the
+    /// default).
+    fn reserve(&self, _amount: u32, _ttl: Duration) -> Option<u64> {
+        None
+    }
+
+    /// Releases whatever is left of reservation `id`. The default does
+    /// nothing.
+    fn cancel_reservation(&self, _id: u64) {}
+
+    /// Drops all accounting for `key`, for when it leaves the pool; stale
+    /// windows for removed keys are harmless but would linger in
+    /// [`RateLimit::status`] and snapshots. The default does nothing.
+    fn forget(&self, _key: &str) {}
+
+    /// Point-in-time budget view per key, for dashboards and schedulers;
+    /// see [`crate::TornClient::rate_limit_status`]. The default returns an
+    /// empty map for limiters that keep no local accounting.
+    fn status(&self) -> RateLimitStatusFuture<'_> {
+        Box::pin(async { HashMap::new() })
+    }
+}
+
+/// Requests allowed per IP per window, as documented by Torn.
+pub const IP_REQUESTS_PER_MINUTE: u32 = 1000;
+
+/// Sliding-window limiter for the per-IP cap, shared *across* clients.
+///
+/// Independent [`crate::TornClient`]s in one process each enforce their own
+/// per-key budgets, but Torn also caps requests per IP. Construct one of
+/// these, wrap it in an [`std::sync::Arc`], and pass it to every client's
+/// configuration so the cap is respected globally.
+#[derive(Debug)]
+pub struct IpRateLimiter {
+    window: Mutex<SecondCounters>,
+    limit: u32,
+    clock: WindowClock,
+    /// FIFO turnstile for AutoDelay waiters, mirroring the per-key
+    /// limiter's fairness queue.
+    turn: Mutex<()>,
+}
+
+impl Default for IpRateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IpRateLimiter {
+    /// Limiter with Torn's documented 1000/minute IP cap.
+    pub fn new() -> Self {
+        Self::with_limit(IP_REQUESTS_PER_MINUTE)
+    }
+
+    /// Limiter with Torn's IP cap reduced by `margin` (a `0.0..1.0`
+    /// fraction): `0.05` stops at 950 of 1000. Holding a few slots back
+    /// keeps clock skew between client and server from causing spurious
+    /// code-5 errors right at the boundary; see
+    /// [`crate::TornClientConfig::rate_limit_margin`] for the per-key
+    /// equivalent.
+    pub fn with_margin(margin: f64) -> Self {
+        Self::with_limit(margined_limit(IP_REQUESTS_PER_MINUTE, margin))
+    }
+
+    /// Limiter with a custom cap, e.g. to leave headroom for other tools on
+    /// the same IP.
+    pub fn with_limit(limit: u32) -> Self {
+        let clock = WindowClock::new();
+        Self {
+            window: Mutex::new(SecondCounters::new(clock.now_secs())),
+            limit,
+            clock,
+            turn: Mutex::new(()),
+        }
+    }
+
+    /// Acquires an IP-wide request slot according to `mode`, with the same
+    /// semantics as the per-key limiter. Public so non-client traffic sharing
+    /// the IP (and benchmarks) can draw from the same budget.
+    pub async fn acquire(&self, mode: RateLimitMode) -> bool {
+        self.acquire_observed(mode, &|_| true).await
+    }
+
+    /// [`IpRateLimiter::acquire`] with each impending wait reported to
+    /// `on_wait`, mirroring [`RateLimit::acquire_observed`].
+    pub async fn acquire_observed(
+        &self,
+        mode: RateLimitMode,
+        on_wait: &(dyn Fn(Duration) -> bool + Sync),
+    ) -> bool {
+        if mode == RateLimitMode::Off {
+            return true;
+        }
+        let _turn = if mode == RateLimitMode::AutoDelay {
+            Some(self.turn.lock().await)
+        } else {
+            None
+        };
+        loop {
+            let wait = {
+                let mut window = self.window.lock().await;
+                let now = self.clock.now_secs();
+                window.advance(now);
+                if window.used() < self.limit {
+                    window.record(now);
+                    return true;
+                }
+                window.until_next_free(now)
+            };
+            if mode == RateLimitMode::Error {
+                return false;
+            }
+            if !on_wait(wait) {
+                return false;
+            }
+            crate::compat::sleep(wait).await;
+        }
+    }
+
+    /// Slots left in the IP-wide window before the cap.
+    pub async fn remaining(&self) -> u32 {
+        let mut window = self.window.lock().await;
+        window.advance(self.clock.now_secs());
+        self.limit.saturating_sub(window.used())
+    }
+}
+
+/// The effective cap once `margin` (a `0.0..1.0` fraction) is held back,
+/// never below one slot.
+pub(crate) fn margined_limit(limit: u32, margin: f64) -> u32 {
+    (f64::from(limit) * (1.0 - margin)).floor().max(1.0) as u32
+}
+
+/// Seconds in one rate limit window.
+const SLOTS_PER_WINDOW: usize = WINDOW.as_secs() as usize;
+
+/// Monotonic seconds with a one-window head start, so restored state can
+/// reference seconds "before" a freshly constructed limiter existed.
+#[derive(Debug, Clone, Copy)]
+struct WindowClock {
+    epoch: Instant,
+}
+
+impl WindowClock {
+    fn new() -> Self {
+        Self {
+            epoch: Instant::now(),
+        }
+    }
+
+    fn now_secs(&self) -> u64 {
+        self.epoch.elapsed().as_secs() + WINDOW.as_secs()
+    }
+}
+
+/// One window of per-second counters, advanced lazily. Recording and
+/// availability checks are O(1) — at worst one fixed-size sweep after an
+/// idle period — where the `Vec<Instant>` windows this replaced re-scanned
+/// up to the full cap under the lock on every call.
+#[derive(Debug, Clone)]
+struct SecondCounters {
+    counts: [u32; SLOTS_PER_WINDOW],
+    total: u32,
+    last_second: u64,
+}
+
+impl SecondCounters {
+    fn new(now: u64) -> Self {
+        Self {
+            counts: [0; SLOTS_PER_WINDOW],
+            total: 0,
+            last_second: now,
+        }
+    }
+
+    /// Expires every slot that has rotated out since the last call.
+    fn advance(&mut self, now: u64) {
+        if now <= self.last_second {
+            return;
+        }
+        if now - self.last_second >= SLOTS_PER_WINDOW as u64 {
+            self.counts = [0; SLOTS_PER_WINDOW];
+            self.total = 0;
+        } else {
+            for second in self.last_second + 1..=now {
+                let slot = (second % SLOTS_PER_WINDOW as u64) as usize;
+                self.total -= self.counts[slot];
+                self.counts[slot] = 0;
+            }
+        }
+        self.last_second = now;
+    }
+
+    /// Requests recorded in the window ending at the last `advance`.
+    fn used(&self) -> u32 {
+        self.total
+    }
+
+    fn record(&mut self, now: u64) {
+        self.advance(now);
+        self.record_at(now, 1);
+    }
+
+    /// Books `count` requests against `second`, which must lie inside the
+    /// current window.
+    fn record_at(&mut self, second: u64, count: u32) {
+        self.counts[(second % SLOTS_PER_WINDOW as u64) as usize] += count;
+        self.total += count;
+    }
+
+    /// Time until the oldest occupied second rotates out; zero on an empty
+    /// window. Only computed when the window is full, so the fixed-size scan
+    /// stays off the hot path.
+    fn until_next_free(&self, now: u64) -> Duration {
+        for age in (0..SLOTS_PER_WINDOW as u64).rev() {
+            let second = now - age;
+            if self.counts[(second % SLOTS_PER_WINDOW as u64) as usize] > 0 {
+                return Duration::from_secs(SLOTS_PER_WINDOW as u64 - age);
+            }
+        }
+        Duration::ZERO
+    }
+
+    /// The age in seconds of the oldest occupied slot, if any.
+    fn oldest_age(&self, now: u64) -> Option<u64> {
+        (0..SLOTS_PER_WINDOW as u64)
+            .rev()
+            .find(|age| self.counts[((now - age) % SLOTS_PER_WINDOW as u64) as usize] > 0)
+    }
+}
+
+/// Sliding-window limiter tracking per-second request counts per key.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    windows: Mutex<HashMap<String, SecondCounters>>,
+    limit: u32,
+    /// Saturation penalties from server-side code 5; a std mutex because it
+    /// is touched from sync error handling and never held across awaits.
+    cold_until: std::sync::Mutex<HashMap<String, Instant>>,
+    clock: WindowClock,
+    /// Per-key waiter queues for AutoDelay; see [`RateLimiter::queues`].
+    queues: std::sync::Mutex<HashMap<String, Arc<KeyQueues>>>,
+    /// Live budget reservations; a std mutex because it is only held for
+    /// short sync bookkeeping.
+    reservations: std::sync::Mutex<HashMap<u64, Reservation>>,
+    next_reservation: std::sync::atomic::AtomicU64,
+}
+
+/// One earmark's live state.
+#[derive(Debug)]
+struct Reservation {
+    remaining: u32,
+    expires: Instant,
+}
+
+/// AutoDelay waiter bookkeeping for one key: a FIFO turnstile per priority
+/// level plus the number of waiters registered at each, so lower-priority
+/// waiters can tell when they must keep deferring.
+#[derive(Debug, Default)]
+struct KeyQueues {
+    turns: [Mutex<()>; 3],
+    waiting: [std::sync::atomic::AtomicUsize; 3],
+}
+
+impl KeyQueues {
+    /// Whether any waiter more urgent than `rank` is registered.
+    fn higher_waiting(&self, rank: usize) -> bool {
+        self.waiting[..rank]
+            .iter()
+            .any(|count| count.load(std::sync::atomic::Ordering::SeqCst) > 0)
+    }
+}
+
+/// Registration of one waiter at one priority level, dropped (also on
+/// cancellation) once the waiter no longer needs lower priorities to defer.
+struct WaitingGuard<'a> {
+    count: &'a std::sync::atomic::AtomicUsize,
+}
+
+impl<'a> WaitingGuard<'a> {
+    fn register(queues: &'a KeyQueues, rank: usize) -> Self {
+        let count = &queues.waiting[rank];
+        count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        Self { count }
+    }
+}
+
+impl Drop for WaitingGuard<'_> {
+    fn drop(&mut self) {
+        self.count.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+impl RateLimiter {
+    /// Limiter with a per-key cap at or below Torn's documented one; the
+    /// client passes the cap left after
+    /// [`crate::TornClientConfig::rate_limit_margin`].
+    pub(crate) fn with_limit(limit: u32) -> Self {
+        Self {
+            windows: Mutex::new(HashMap::new()),
+            limit,
+            cold_until: std::sync::Mutex::new(HashMap::new()),
+            clock: WindowClock::new(),
+            queues: std::sync::Mutex::new(HashMap::new()),
+            reservations: std::sync::Mutex::new(HashMap::new()),
+            next_reservation: std::sync::atomic::AtomicU64::new(0),
+        }
+    }
+
+    /// The waiter queues for `key`. Within one priority level waiters hold a
+    /// tokio `Mutex` as a turnstile — it is fair, granting the lock in the
+    /// order it was requested — so slots are served roughly in arrival order
+    /// instead of letting whoever wakes first win.
+    fn queues(&self, key: &str) -> Arc<KeyQueues> {
+        let mut queues = self.queues.lock().expect("queue map poisoned");
+        Arc::clone(queues.entry(key.to_owned()).or_default())
+    }
+
+    fn entry<'a>(
+        windows: &'a mut HashMap<String, SecondCounters>,
+        key: &str,
+        now: u64,
+    ) -> &'a mut SecondCounters {
+        let counters = windows
+            .entry(key.to_owned())
+            .or_insert_with(|| SecondCounters::new(now));
+        counters.advance(now);
+        counters
+    }
+
+    /// How long `key`'s saturation penalty still has to run, if any.
+    fn cold_remaining(&self, key: &str) -> Option<Duration> {
+        let cold = self.cold_until.lock().expect("cold map poisoned");
+        cold.get(key)?.checked_duration_since(Instant::now())
+    }
+
+    /// Unexpired earmarked slots other than reservation `exclude`'s,
+    /// pruning spent and expired entries along the way.
+    fn reserved_remaining(&self, exclude: Option<u64>) -> u32 {
+        let mut reservations = self.reservations.lock().expect("reservation map poisoned");
+        let now = Instant::now();
+        reservations.retain(|_, r| r.remaining > 0 && r.expires > now);
+        reservations
+            .iter()
+            .filter(|(id, _)| Some(**id) != exclude)
+            .map(|(_, r)| r.remaining)
+            .sum()
+    }
+
+    /// Consumes one slot from reservation `id`; `false` once it is spent,
+    /// expired or cancelled (the holder then competes normally).
+    fn consume_reservation(&self, id: u64) -> bool {
+        let mut reservations = self.reservations.lock().expect("reservation map poisoned");
+        match reservations.get_mut(&id) {
+            Some(r) if r.remaining > 0 && r.expires > Instant::now() => {
+                r.remaining -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Point-in-time budget view for `key`.
+    pub(crate) async fn get_rate_limit_info(&self, key: &str) -> RateLimitInfo {
+        let cold_remaining = self.cold_remaining(key);
+        let mut windows = self.windows.lock().await;
+        let counters = Self::entry(&mut windows, key, self.clock.now_secs());
+        let used = counters.used();
+        let remaining = if cold_remaining.is_some() {
+            0
+        } else {
+            self.limit
+                .saturating_sub(self.reserved_remaining(None))
+                .saturating_sub(used)
+        };
+        RateLimitInfo {
+            label: None,
+            used,
+            remaining,
+            cold_remaining,
+        }
+    }
+
+    pub(crate) async fn acquire(&self, key: &str, mode: RateLimitMode) -> bool {
+        self.acquire_observed(key, mode, &|_| true).await
+    }
+
+    pub(crate) async fn acquire_observed(
+        &self,
+        key: &str,
+        mode: RateLimitMode,
+        on_wait: &(dyn Fn(Duration) -> bool + Sync),
+    ) -> bool {
+        self.acquire_with(key, mode, AcquireContext::default(), on_wait)
+            .await
+    }
+
+    pub(crate) async fn acquire_with(
+        &self,
+        key: &str,
+        mode: RateLimitMode,
+        context: AcquireContext,
+        on_wait: &(dyn Fn(Duration) -> bool + Sync),
+    ) -> bool {
+        if mode == RateLimitMode::Off {
+            return true;
+        }
+        // Error mode never waits, so it probes the window directly and
+        // skips the queues.
+        let rank = context.priority.rank();
+        let queues = (mode == RateLimitMode::AutoDelay).then(|| self.queues(key));
+        let _registration = queues
+            .as_deref()
+            .map(|queues| WaitingGuard::register(queues, rank));
+        let _turn = match queues.as_deref() {
+            Some(queues) => Some(queues.turns[rank].lock().await),
+            None => None,
+        };
+        loop {
+            if let Some(wait) = self.cold_remaining(key) {
+                if mode == RateLimitMode::Error {
+                    return false;
+                }
+                if !on_wait(wait) {
+                    return false;
+                }
+                crate::compat::sleep(wait).await;
+                continue;
+            }
+            let wait = {
+                let mut windows = self.windows.lock().await;
+                let now = self.clock.now_secs();
+                let counters = Self::entry(&mut windows, key, now);
+                // Slots earmarked by other holders are off the table; a
+                // call drawing from its own reservation runs at the full
+                // cap and consumes the earmark.
+                let headroom = self
+                    .limit
+                    .saturating_sub(self.reserved_remaining(context.reservation));
+                if counters.used() < headroom {
+                    let deferring = queues
+                        .as_deref()
+                        .is_some_and(|queues| queues.higher_waiting(rank));
+                    if !deferring {
+                        if let Some(id) = context.reservation {
+                            self.consume_reservation(id);
+                        }
+                        counters.record(now);
+                        return true;
+                    }
+                    // A more urgent waiter gets first claim on the free
+                    // slot; check back shortly rather than a full window.
+                    PRIORITY_DEFER_POLL
+                } else {
+                    // Oldest occupied second decides when the next slot
+                    // opens.
+                    counters.until_next_free(now)
+                }
+            };
+            if mode == RateLimitMode::Error {
+                return false;
+            }
+            if !on_wait(wait) {
+                return false;
+            }
+            crate::compat::sleep(wait).await;
+        }
+    }
+}
+
+impl RateLimit for RateLimiter {
+    fn acquire<'a>(&'a self, key: &'a str, mode: RateLimitMode) -> RateLimitFuture<'a> {
+        Box::pin(RateLimiter::acquire(self, key, mode))
+    }
+
+    fn acquire_observed<'a>(
+        &'a self,
+        key: &'a str,
+        mode: RateLimitMode,
+        on_wait: &'a (dyn Fn(Duration) -> bool + Sync),
+    ) -> RateLimitFuture<'a> {
+        Box::pin(RateLimiter::acquire_observed(self, key, mode, on_wait))
+    }
+
+    fn acquire_with<'a>(
+        &'a self,
+        key: &'a str,
+        mode: RateLimitMode,
+        context: AcquireContext,
+        on_wait: &'a (dyn Fn(Duration) -> bool + Sync),
+    ) -> RateLimitFuture<'a> {
+        Box::pin(RateLimiter::acquire_with(self, key, mode, context, on_wait))
+    }
+
+    fn reserve(&self, amount: u32, ttl: Duration) -> Option<u64> {
+        let id = self
+            .next_reservation
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        self.reservations
+            .lock()
+            .expect("reservation map poisoned")
+            .insert(
+                id,
+                Reservation {
+                    remaining: amount,
+                    expires: Instant::now() + ttl,
+                },
+            );
+        Some(id)
+    }
+
+    fn cancel_reservation(&self, id: u64) {
+        self.reservations
+            .lock()
+            .expect("reservation map poisoned")
+            .remove(&id);
+    }
+
+    fn forget(&self, key: &str) {
+        // try_lock: key removal is rare; leaving a stale window behind under
+        // live contention beats blocking inside a sync call.
+        if let Ok(mut windows) = self.windows.try_lock() {
+            windows.remove(key);
+        }
+        self.cold_until
+            .lock()
+            .expect("cold map poisoned")
+            .remove(key);
+        self.queues.lock().expect("queue map poisoned").remove(key);
+    }
+
+    fn status(&self) -> RateLimitStatusFuture<'_> {
+        Box::pin(async {
+            let mut keys: Vec<String> = self.windows.lock().await.keys().cloned().collect();
+            // A key can be cold without a window entry (penalized before it
+            // ever acquired); it is tracked state all the same.
+            for key in self.cold_until.lock().expect("cold map poisoned").keys() {
+                if !keys.contains(key) {
+                    keys.push(key.clone());
+                }
+            }
+            let mut status = HashMap::with_capacity(keys.len());
+            for key in keys {
+                let info = self.get_rate_limit_info(&key).await;
+                status.insert(key, info);
+            }
+            status
+        })
+    }
+
+    fn snapshot(&self) -> Option<RateLimiterSnapshot> {
+        // try_lock: snapshots happen at shutdown; skipping one under live
+        // contention beats blocking inside a sync call.
+        let mut windows = self.windows.try_lock().ok()?;
+        let now = self.clock.now_secs();
+        let now_unix = crate::client::local_unix_now();
+        let windows = windows
+            .iter_mut()
+            .map(|(key, counters)| {
+                counters.advance(now);
+                let mut times = Vec::with_capacity(counters.used() as usize);
+                for age in 0..SLOTS_PER_WINDOW as u64 {
+                    let second = now - age;
+                    let count = counters.counts[(second % SLOTS_PER_WINDOW as u64) as usize];
+                    times.extend(std::iter::repeat_n(now_unix - age as i64, count as usize));
+                }
+                (key.clone(), times)
+            })
+            .collect();
+        Some(RateLimiterSnapshot { windows })
+    }
+
+    fn penalize(&self, key: &str) {
+        // The server's window start is unknowable; the oldest locally
+        // recorded request bounds how much of it can still be ahead. With no
+        // local record, assume a full window.
+        let now = Instant::now();
+        let cold_for = self
+            .windows
+            .try_lock()
+            .ok()
+            .and_then(|windows| {
+                let counters = windows.get(key)?;
+                let age = counters.oldest_age(self.clock.now_secs())?;
+                Some(WINDOW - Duration::from_secs(age))
+            })
+            .unwrap_or(WINDOW);
+        let until = now + cold_for;
+        let mut cold = self.cold_until.lock().expect("cold map poisoned");
+        let slot = cold.entry(key.to_owned()).or_insert(until);
+        *slot = (*slot).max(until);
+        tracing::warn!(
+            cold_ms = until.saturating_duration_since(now).as_millis() as u64,
+            "server rejected key with code 5; marking it cold"
+        );
+    }
+
+    fn restore(&self, snapshot: RateLimiterSnapshot) {
+        let Ok(mut windows) = self.windows.try_lock() else {
+            tracing::warn!("rate limiter busy; skipping state restore");
+            return;
+        };
+        let now = self.clock.now_secs();
+        let now_unix = crate::client::local_unix_now();
+        for (key, times) in snapshot.windows {
+            let counters = Self::entry(&mut windows, &key, now);
+            for t in times {
+                let Ok(age) = u64::try_from(now_unix - t) else {
+                    continue;
+                };
+                if age < SLOTS_PER_WINDOW as u64 {
+                    counters.record_at(now - age, 1);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn error_mode_rejects_when_window_full() {
+        let limiter = RateLimiter::with_limit(REQUESTS_PER_MINUTE);
+        for _ in 0..REQUESTS_PER_MINUTE {
+            assert!(limiter.acquire("k", RateLimitMode::Error).await);
+        }
+        assert!(!limiter.acquire("k", RateLimitMode::Error).await);
+        // A different key has its own window.
+        assert!(limiter.acquire("other", RateLimitMode::Error).await);
+    }
+
+    #[tokio::test]
+    async fn ip_limiter_is_shared_across_callers() {
+        let limiter = IpRateLimiter::with_limit(2);
+        assert!(limiter.acquire(RateLimitMode::Error).await);
+        assert!(limiter.acquire(RateLimitMode::Error).await);
+        assert!(!limiter.acquire(RateLimitMode::Error).await);
+    }
+
+    #[tokio::test]
+    async fn penalized_key_is_cold_until_the_window_passes() {
+        let limiter = RateLimiter::with_limit(REQUESTS_PER_MINUTE);
+        assert!(limiter.acquire("k", RateLimitMode::Error).await);
+        RateLimit::penalize(&limiter, "k");
+        assert!(!limiter.acquire("k", RateLimitMode::Error).await);
+        assert!(limiter.acquire("other", RateLimitMode::Error).await);
+
+        let info = limiter.get_rate_limit_info("k").await;
+        assert_eq!(info.remaining, 0);
+        assert!(info.cold_remaining.is_some());
+    }
+
+    #[tokio::test]
+    async fn restored_state_counts_against_the_window() {
+        let limiter = RateLimiter::with_limit(REQUESTS_PER_MINUTE);
+        for _ in 0..REQUESTS_PER_MINUTE {
+            assert!(limiter.acquire("k", RateLimitMode::Error).await);
+        }
+        let snapshot = RateLimit::snapshot(&limiter).expect("uncontended snapshot");
+        assert_eq!(snapshot.windows["k"].len(), REQUESTS_PER_MINUTE as usize);
+
+        let restarted = RateLimiter::with_limit(REQUESTS_PER_MINUTE);
+        RateLimit::restore(&restarted, snapshot);
+        assert!(!restarted.acquire("k", RateLimitMode::Error).await);
+        assert!(restarted.acquire("other", RateLimitMode::Error).await);
+    }
+
+    #[tokio::test]
+    async fn auto_delay_waiters_are_served_in_arrival_order() {
+        // Saturate the window one second short of expiry so all three
+        // waiters queue, then drain through the turnstile together.
+        let age = WINDOW.as_secs() as i64 - 1;
+        let times = vec![crate::client::local_unix_now() - age; REQUESTS_PER_MINUTE as usize];
+        let limiter = Arc::new(RateLimiter::with_limit(REQUESTS_PER_MINUTE));
+        RateLimit::restore(
+            &*limiter,
+            RateLimiterSnapshot {
+                windows: HashMap::from([("k".to_owned(), times)]),
+            },
+        );
+
+        let order = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut tasks = Vec::new();
+        for index in 0..3 {
+            let limiter = Arc::clone(&limiter);
+            let order = Arc::clone(&order);
+            tasks.push(tokio::spawn(async move {
+                assert!(limiter.acquire("k", RateLimitMode::AutoDelay).await);
+                order.lock().unwrap().push(index);
+            }));
+            // Let the task park on the turnstile before spawning the next,
+            // so arrival order is deterministic.
+            tokio::task::yield_now().await;
+        }
+        for task in tasks {
+            task.await.unwrap();
+        }
+        assert_eq!(*order.lock().unwrap(), vec![0, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn high_priority_waiters_claim_freed_slots_first() {
+        // Two slots, both one second from expiry: a Background waiter
+        // arrives first, then a High one; the High waiter must still win
+        // the first freed slot.
+        let age = WINDOW.as_secs() as i64 - 1;
+        let times = vec![crate::client::local_unix_now() - age; 2];
+        let limiter = Arc::new(RateLimiter::with_limit(2));
+        RateLimit::restore(
+            &*limiter,
+            RateLimiterSnapshot {
+                windows: HashMap::from([("k".to_owned(), times)]),
+            },
+        );
+
+        let order = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut tasks = Vec::new();
+        for priority in [Priority::Background, Priority::High] {
+            let limiter = Arc::clone(&limiter);
+            let order = Arc::clone(&order);
+            tasks.push(tokio::spawn(async move {
+                let context = AcquireContext {
+                    priority,
+                    ..AcquireContext::default()
+                };
+                assert!(
+                    limiter
+                        .acquire_with("k", RateLimitMode::AutoDelay, context, &|_| true)
+                        .await
+                );
+                order.lock().unwrap().push(priority);
+            }));
+            tokio::task::yield_now().await;
+        }
+        for task in tasks {
+            task.await.unwrap();
+        }
+        assert_eq!(
+            *order.lock().unwrap(),
+            vec![Priority::High, Priority::Background]
+        );
+    }
+
+    #[tokio::test]
+    async fn auto_delay_reports_waits_to_the_observer() {
+        // A window restored one second short of expiry keeps the real sleep
+        // in this test to about a second.
+        let age = WINDOW.as_secs() as i64 - 1;
+        let times = vec![crate::client::local_unix_now() - age; REQUESTS_PER_MINUTE as usize];
+        let limiter = RateLimiter::with_limit(REQUESTS_PER_MINUTE);
+        RateLimit::restore(
+            &limiter,
+            RateLimiterSnapshot {
+                windows: HashMap::from([("k".to_owned(), times)]),
+            },
+        );
+
+        let waits = std::sync::Mutex::new(Vec::new());
+        let on_wait = |wait: Duration| {
+            waits.lock().unwrap().push(wait);
+            true
+        };
+        assert!(
+            limiter
+                .acquire_observed("k", RateLimitMode::AutoDelay, &on_wait)
+                .await
+        );
+        let waits = waits.into_inner().unwrap();
+        assert!(!waits.is_empty());
+        assert!(waits[0] <= Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn margin_stops_short_of_the_documented_cap() {
+        let limit = margined_limit(REQUESTS_PER_MINUTE, 0.05);
+        assert_eq!(limit, 95);
+        let limiter = RateLimiter::with_limit(limit);
+        for _ in 0..limit {
+            assert!(limiter.acquire("k", RateLimitMode::Error).await);
+        }
+        assert!(!limiter.acquire("k", RateLimitMode::Error).await);
+
+        let ip = IpRateLimiter::with_margin(0.05);
+        assert_eq!(ip.remaining().await, 950);
+    }
+
+    #[tokio::test]
+    async fn forget_drops_all_state_for_a_removed_key() {
+        let limiter = RateLimiter::with_limit(REQUESTS_PER_MINUTE);
+        assert!(limiter.acquire("gone", RateLimitMode::Error).await);
+        assert!(limiter.acquire("kept", RateLimitMode::Error).await);
+        RateLimit::penalize(&limiter, "gone");
+
+        RateLimit::forget(&limiter, "gone");
+        let status = RateLimit::status(&limiter).await;
+        assert!(!status.contains_key("gone"));
+        assert_eq!(status["kept"].used, 1);
+        // No lingering cold penalty either: the key is usable if re-added.
+        assert!(limiter.acquire("gone", RateLimitMode::Error).await);
+    }
+
+    #[tokio::test]
+    async fn reservations_earmark_slots_for_their_holder() {
+        let limiter = RateLimiter::with_limit(3);
+        let id = RateLimit::reserve(&limiter, 2, Duration::from_secs(60)).expect("supported");
+
+        // Unreserved traffic only sees what the earmark left over.
+        let info = limiter.get_rate_limit_info("k").await;
+        assert_eq!(info.remaining, 1);
+        assert!(limiter.acquire("k", RateLimitMode::Error).await);
+        assert!(!limiter.acquire("k", RateLimitMode::Error).await);
+
+        // The holder runs at the full cap until the earmark is spent.
+        let context = AcquireContext {
+            reservation: Some(id),
+            ..AcquireContext::default()
+        };
+        for _ in 0..2 {
+            assert!(
+                limiter
+                    .acquire_with("k", RateLimitMode::Error, context, &|_| true)
+                    .await
+            );
+        }
+        assert!(
+            !limiter
+                .acquire_with("k", RateLimitMode::Error, context, &|_| true)
+                .await
+        );
+    }
+
+    #[tokio::test]
+    async fn cancelled_reservations_release_their_headroom() {
+        let limiter = RateLimiter::with_limit(2);
+        let id = RateLimit::reserve(&limiter, 2, Duration::from_secs(60)).expect("supported");
+        assert!(!limiter.acquire("k", RateLimitMode::Error).await);
+        RateLimit::cancel_reservation(&limiter, id);
+        assert!(limiter.acquire("k", RateLimitMode::Error).await);
+    }
+
+    #[tokio::test]
+    async fn observer_refusal_abandons_the_acquisition() {
+        let limiter = RateLimiter::with_limit(REQUESTS_PER_MINUTE);
+        for _ in 0..REQUESTS_PER_MINUTE {
+            assert!(limiter.acquire("k", RateLimitMode::Error).await);
+        }
+        // A caller unwilling to wait gets a fast `false` instead of a sleep.
+        assert!(
+            !limiter
+                .acquire_observed("k", RateLimitMode::AutoDelay, &|_| false)
+                .await
+        );
+    }
+
+    #[tokio::test]
+    async fn status_reports_every_tracked_key() {
+        let limiter = RateLimiter::with_limit(REQUESTS_PER_MINUTE);
+        for _ in 0..3 {
+            assert!(limiter.acquire("a", RateLimitMode::Error).await);
+        }
+        assert!(limiter.acquire("b", RateLimitMode::Error).await);
+
+        let status = RateLimit::status(&limiter).await;
+        assert_eq!(status.len(), 2);
+        assert_eq!(status["a"].used, 3);
+        assert_eq!(status["a"].remaining, REQUESTS_PER_MINUTE - 3);
+        assert_eq!(status["b"].used, 1);
+
+        let ip = IpRateLimiter::with_limit(10);
+        assert!(ip.acquire(RateLimitMode::Error).await);
+        assert_eq!(ip.remaining().await, 9);
+    }
+
+    #[tokio::test]
+    async fn off_mode_never_blocks() {
+        let limiter = RateLimiter::with_limit(REQUESTS_PER_MINUTE);
+        for _ in 0..REQUESTS_PER_MINUTE * 2 {
+            assert!(limiter.acquire("k", RateLimitMode::Off).await);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Redis-backed distributed rate limiting (the `redis-rate-limit` feature).
+//!
+//! Several service instances behind one IP each enforcing their own
+//! in-process budget collectively blow past Torn's caps. [`RedisRateLimiter`]
+//! implements [`RateLimit`] against a shared Redis, so every instance draws
+//! from the same fixed per-key window: install it on each client via
+//! [`crate::TornClientConfig::rate_limiter`].
+//!
+//! Accounting uses atomic `INCR` on a per-key, per-window counter with a
+//! TTL — a fixed window rather than the in-process limiter's sliding one,
+//! because it needs exactly one round trip per acquisition. If Redis is
+//! unreachable the limiter fails open (the request proceeds) and logs a
+//! warning: a degraded shared budget is recoverable, a hard dependency on
+//! Redis for every API call is not.
+
+use std::time::Duration;
+
+use redis::AsyncCommands;
+use tokio::sync::OnceCell;
+
+use crate::rate_limit::{RateLimit, RateLimitFuture, RateLimitMode, REQUESTS_PER_MINUTE};
+
+const WINDOW: Duration = Duration::from_secs(60);
+
+/// A [`RateLimit`] implementation sharing one budget across processes.
+#[derive(Debug)]
+pub struct RedisRateLimiter {
+    client: redis::Client,
+    connection: OnceCell<redis::aio::MultiplexedConnection>,
+    limit: u32,
+    prefix: String,
+}
+
+impl RedisRateLimiter {
+    /// Limiter against the Redis at `url` (e.g. `redis://127.0.0.1/`) with
+    /// Torn's documented 100/minute per-key cap.
+    pub fn new(url: &str) -> crate::Result<Self> {
+        Self::with_limit(url, REQUESTS_PER_MINUTE)
+    }
+
+    /// Limiter with a custom per-key cap, e.g. to leave headroom for
+    /// instances not sharing this Redis.
+    pub fn with_limit(url: &str, limit: u32) -> crate::Result<Self> {
+        let client = redis::Client::open(url)
+            .map_err(|e| crate::TornError::InvalidParams(format!("invalid redis url: {e}")))?;
+        Ok(Self {
+            client,
+            connection: OnceCell::new(),
+            limit,
+            prefix: "torn-client:rate".to_owned(),
+        })
+    }
+
+    /// Overrides the Redis key prefix (default `torn-client:rate`), so
+    /// unrelated deployments can share one Redis without sharing budgets.
+    pub fn key_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// One `INCR` against the current window's counter. Returns `None` when
+    /// a slot was taken, or how long until the window rolls over when the
+    /// shared budget is exhausted.
+    async fn try_acquire(&self, key: &str) -> Result<Option<Duration>, redis::RedisError> {
+        let mut connection = self
+            .connection
+            .get_or_try_init(|| self.client.get_multiplexed_async_connection())
+            .await?
+            .clone();
+        let now = crate::client::local_unix_now();
+        let bucket = now.div_euclid(WINDOW.as_secs() as i64);
+        let counter = format!("{}:{}:{}", self.prefix, key, bucket);
+        let count: u32 = connection.incr(&counter, 1u32).await?;
+        if count == 1 {
+            // Two windows of TTL so a clock-skewed instance cannot resurrect
+            // an expired counter.
+            let _: bool = connection
+                .expire(&counter, 2 * WINDOW.as_secs() as i64)
+                .await?;
+        }
+        if count <= self.limit {
+            return Ok(None);
+        }
+        let into_window = now.rem_euclid(WINDOW.as_secs() as i64) as u64;
+        Ok(Some(Duration::from_secs(WINDOW.as_secs() - into_window)))
+    }
+}
+
+impl RateLimit for RedisRateLimiter {
+    fn acquire<'a>(&'a self, key: &'a str, mode: RateLimitMode) -> RateLimitFuture<'a> {
+        Box::pin(async move {
+            if mode == RateLimitMode::Off {
+                return true;
+            }
+            loop {
+                let wait = match self.try_acquire(key).await {
+                    Ok(None) => return true,
+                    Ok(Some(wait)) => wait,
+                    Err(e) => {
+                        tracing::warn!(error = %e, "redis rate limiter unreachable; failing open");
+                        return true;
+                    }
+                };
+                if mode == RateLimitMode::Error {
+                    return false;
+                }
+                crate::compat::sleep(wait).await;
+            }
+        })
+    }
+}
@@ -0,0 +1,4 @@
+//! Persisting API responses into local databases.
+
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
@@ -0,0 +1,300 @@
+//! SQLite persistence for core entities (`sqlite` feature).
+//!
+//! Ready-made tables plus insert/upsert helpers that consume the typed
+//! responses directly, so every faction tool does not have to design the
+//! same schema again. All helpers are idempotent: rows are keyed on their
+//! API ids and re-running a sync upserts rather than duplicates.
+
+use rusqlite::{params, Connection};
+
+use crate::models::faction::{FactionBasic, FactionMember};
+use crate::models::market::ItemMarketListing;
+use crate::models::torn::Item;
+use crate::models::user::{Attack, UserProfile};
+
+/// The DDL applied by [`init_schema`], exposed for migration tooling.
+pub const SCHEMA: &str = "\
+CREATE TABLE IF NOT EXISTS users (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    level INTEGER NOT NULL,
+    gender TEXT NOT NULL,
+    last_action INTEGER NOT NULL,
+    status TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS factions (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    tag TEXT NOT NULL,
+    leader_id INTEGER NOT NULL,
+    respect INTEGER NOT NULL,
+    members INTEGER NOT NULL,
+    capacity INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS faction_members (
+    id INTEGER PRIMARY KEY,
+    faction_id INTEGER NOT NULL REFERENCES factions(id),
+    name TEXT NOT NULL,
+    level INTEGER NOT NULL,
+    position TEXT NOT NULL,
+    days_in_faction INTEGER NOT NULL,
+    is_revivable INTEGER NOT NULL,
+    last_action INTEGER NOT NULL,
+    status TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS attacks (
+    id INTEGER PRIMARY KEY,
+    code TEXT NOT NULL,
+    started INTEGER NOT NULL,
+    ended INTEGER NOT NULL,
+    attacker_id INTEGER,
+    defender_id INTEGER,
+    result TEXT NOT NULL,
+    respect_gain REAL NOT NULL,
+    respect_loss REAL NOT NULL,
+    chain INTEGER NOT NULL,
+    is_stealthed INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS items (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    type TEXT NOT NULL,
+    circulation INTEGER NOT NULL,
+    market_price INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS market_listings (
+    id INTEGER NOT NULL,
+    item_id INTEGER NOT NULL,
+    price INTEGER NOT NULL,
+    amount INTEGER NOT NULL,
+    observed_at INTEGER NOT NULL,
+    PRIMARY KEY (id, observed_at)
+);
+";
+
+/// Creates all tables if they do not exist yet.
+pub fn init_schema(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(SCHEMA)
+}
+
+/// Upserts one user profile into `users`.
+pub fn upsert_user(conn: &Connection, profile: &UserProfile) -> rusqlite::Result<()> {
+    conn.execute(
+        "INSERT INTO users (id, name, level, gender, last_action, status)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+         ON CONFLICT(id) DO UPDATE SET
+             name = excluded.name, level = excluded.level, gender = excluded.gender,
+             last_action = excluded.last_action, status = excluded.status",
+        params![
+            profile.player_id as i64,
+            profile.name,
+            profile.level,
+            profile.gender,
+            profile.last_action.timestamp,
+            profile.status.description,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Upserts one faction into `factions`.
+pub fn upsert_faction(conn: &Connection, faction: &FactionBasic) -> rusqlite::Result<()> {
+    conn.execute(
+        "INSERT INTO factions (id, name, tag, leader_id, respect, members, capacity)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+         ON CONFLICT(id) DO UPDATE SET
+             name = excluded.name, tag = excluded.tag, leader_id = excluded.leader_id,
+             respect = excluded.respect, members = excluded.members,
+             capacity = excluded.capacity",
+        params![
+            faction.id as i64,
+            faction.name,
+            faction.tag,
+            faction.leader_id as i64,
+            faction.respect as i64,
+            faction.members,
+            faction.capacity,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Upserts a faction's member roster. Returns the number of rows written.
+pub fn upsert_faction_members(
+    conn: &Connection,
+    faction_id: u64,
+    members: &[FactionMember],
+) -> rusqlite::Result<usize> {
+    let mut stmt = conn.prepare(
+        "INSERT INTO faction_members
+             (id, faction_id, name, level, position, days_in_faction, is_revivable,
+              last_action, status)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+         ON CONFLICT(id) DO UPDATE SET
+             faction_id = excluded.faction_id, name = excluded.name,
+             level = excluded.level, position = excluded.position,
+             days_in_faction = excluded.days_in_faction,
+             is_revivable = excluded.is_revivable,
+             last_action = excluded.last_action, status = excluded.status",
+    )?;
+    for member in members {
+        stmt.execute(params![
+            member.id as i64,
+            faction_id as i64,
+            member.name,
+            member.level,
+            member.position,
+            member.days_in_faction,
+            member.is_revivable,
+            member.last_action.timestamp,
+            member.status.description,
+        ])?;
+    }
+    Ok(members.len())
+}
+
+/// Inserts attacks, ignoring ids already stored. Returns newly inserted rows.
+pub fn insert_attacks(conn: &Connection, attacks: &[Attack]) -> rusqlite::Result<usize> {
+    let mut stmt = conn.prepare(
+        "INSERT OR IGNORE INTO attacks
+             (id, code, started, ended, attacker_id, defender_id, result,
+              respect_gain, respect_loss, chain, is_stealthed)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+    )?;
+    let mut inserted = 0;
+    for attack in attacks {
+        inserted += stmt.execute(params![
+            attack.id as i64,
+            attack.code,
+            attack.started,
+            attack.ended,
+            attack
+                .attacker
+                .as_ref()
+                .and_then(|a| a.id)
+                .map(|id| id as i64),
+            attack.defender.id.map(|id| id as i64),
+            attack.result,
+            attack.respect_gain,
+            attack.respect_loss,
+            attack.chain,
+            attack.is_stealthed,
+        ])?;
+    }
+    Ok(inserted)
+}
+
+/// Upserts the item catalog.
+pub fn upsert_items(conn: &Connection, items: &[Item]) -> rusqlite::Result<usize> {
+    let mut stmt = conn.prepare(
+        "INSERT INTO items (id, name, type, circulation, market_price)
+         VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT(id) DO UPDATE SET
+             name = excluded.name, type = excluded.type,
+             circulation = excluded.circulation,
+             market_price = excluded.market_price",
+    )?;
+    for item in items {
+        stmt.execute(params![
+            item.id as i64,
+            item.name,
+            item.item_type,
+            item.circulation as i64,
+            item.value.market_price,
+        ])?;
+    }
+    Ok(items.len())
+}
+
+/// Records a snapshot of market listings for an item at `observed_at`.
+pub fn insert_market_listings(
+    conn: &Connection,
+    item_id: u64,
+    observed_at: i64,
+    listings: &[ItemMarketListing],
+) -> rusqlite::Result<usize> {
+    let mut stmt = conn.prepare(
+        "INSERT OR IGNORE INTO market_listings (id, item_id, price, amount, observed_at)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+    )?;
+    let mut inserted = 0;
+    for listing in listings {
+        inserted += stmt.execute(params![
+            listing.id as i64,
+            item_id as i64,
+            listing.price,
+            listing.amount,
+            observed_at,
+        ])?;
+    }
+    Ok(inserted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::faction::FactionBasic;
+
+    fn conn() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        init_schema(&conn).unwrap();
+        conn
+    }
+
+    fn faction() -> FactionBasic {
+        FactionBasic {
+            id: 42,
+            name: "Test".into(),
+            tag: "TST".into(),
+            leader_id: 1,
+            respect: 1000,
+            members: 10,
+            capacity: 25,
+        }
+    }
+
+    #[test]
+    fn schema_is_idempotent() {
+        let conn = conn();
+        init_schema(&conn).unwrap();
+    }
+
+    #[test]
+    fn faction_upsert_updates_in_place() {
+        let conn = conn();
+        upsert_faction(&conn, &faction()).unwrap();
+        let mut updated = faction();
+        updated.respect = 2000;
+        upsert_faction(&conn, &updated).unwrap();
+        let (count, respect): (i64, i64) = conn
+            .query_row("SELECT COUNT(*), MAX(respect) FROM factions", [], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+        assert_eq!(count, 1);
+        assert_eq!(respect, 2000);
+    }
+
+    #[test]
+    fn market_snapshots_keep_history() {
+        let conn = conn();
+        let listing = ItemMarketListing {
+            id: 7,
+            price: 1000,
+            amount: 1,
+        };
+        assert_eq!(
+            insert_market_listings(&conn, 206, 100, std::slice::from_ref(&listing)).unwrap(),
+            1
+        );
+        // Same listing at a later observation is a new row; a replay is not.
+        assert_eq!(
+            insert_market_listings(&conn, 206, 200, std::slice::from_ref(&listing)).unwrap(),
+            1
+        );
+        assert_eq!(
+            insert_market_listings(&conn, 206, 200, &[listing]).unwrap(),
+            0
+        );
+    }
+}
@@ -0,0 +1,96 @@
+//! Torn City Time (TCT) helpers.
+//!
+//! TCT is UTC; game events key off its day and tick boundaries (daily resets
+//! at TCT midnight, races on 15-minute ticks). These helpers turn a unix
+//! timestamp — ideally [`crate::TornClient::torn_now`], which corrects for
+//! clock skew — into the boundaries schedulers and monitors care about, so
+//! every event-driven tool stops re-deriving them.
+
+use std::time::Duration;
+
+/// Seconds in a TCT day.
+pub const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Interval between race ticks.
+pub const RACE_TICK: Duration = Duration::from_secs(15 * 60);
+
+/// The TCT midnight at or before `now` (start of the current game day).
+pub fn day_start(now: i64) -> i64 {
+    now - now.rem_euclid(SECONDS_PER_DAY)
+}
+
+/// The next TCT midnight strictly after `now` — the daily reset boundary.
+pub fn next_midnight(now: i64) -> i64 {
+    day_start(now) + SECONDS_PER_DAY
+}
+
+/// Time until the next TCT midnight.
+pub fn until_next_midnight(now: i64) -> Duration {
+    Duration::from_secs((next_midnight(now) - now) as u64)
+}
+
+/// The next boundary of an `interval` grid (anchored at TCT midnight)
+/// strictly after `now`, e.g. the next hourly or 15-minute tick.
+pub fn next_tick(now: i64, interval: Duration) -> i64 {
+    let interval = interval.as_secs().max(1) as i64;
+    now - now.rem_euclid(interval) + interval
+}
+
+/// Time until the next 15-minute race tick.
+pub fn until_next_race_tick(now: i64) -> Duration {
+    Duration::from_secs((next_tick(now, RACE_TICK) - now) as u64)
+}
+
+/// The wall-clock time of day in TCT as `(hours, minutes, seconds)`.
+pub fn time_of_day(now: i64) -> (u8, u8, u8) {
+    let of_day = now.rem_euclid(SECONDS_PER_DAY);
+    (
+        (of_day / 3600) as u8,
+        (of_day % 3600 / 60) as u8,
+        (of_day % 60) as u8,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2023-11-14 22:13:20 UTC.
+    const NOW: i64 = 1_700_000_000;
+
+    #[test]
+    fn midnight_boundaries_bracket_now() {
+        let start = day_start(NOW);
+        let next = next_midnight(NOW);
+        assert_eq!(start % SECONDS_PER_DAY, 0);
+        assert_eq!(next - start, SECONDS_PER_DAY);
+        assert!(start <= NOW && NOW < next);
+        assert_eq!(
+            until_next_midnight(NOW),
+            Duration::from_secs((next - NOW) as u64)
+        );
+    }
+
+    #[test]
+    fn race_ticks_land_on_the_quarter_hour_grid() {
+        let tick = next_tick(NOW, RACE_TICK);
+        assert_eq!(tick % (15 * 60), 0);
+        assert!(tick > NOW);
+        assert!(tick - NOW <= 15 * 60);
+    }
+
+    #[test]
+    fn exact_boundary_rolls_to_the_next_tick() {
+        let boundary = day_start(NOW);
+        assert_eq!(next_midnight(boundary), boundary + SECONDS_PER_DAY);
+        assert_eq!(
+            next_tick(boundary, RACE_TICK),
+            boundary + RACE_TICK.as_secs() as i64
+        );
+    }
+
+    #[test]
+    fn time_of_day_matches_known_timestamp() {
+        assert_eq!(time_of_day(NOW), (22, 13, 20));
+    }
+}
@@ -0,0 +1,232 @@
+//! Aggregate usage accounting since client construction.
+//!
+//! Every request feeds counters summarized by
+//! [`crate::TornClient::usage_report`] — handy to print at bot shutdown or
+//! expose through an admin command.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+/// Snapshot returned by [`crate::TornClient::usage_report`].
+#[derive(Debug, Clone)]
+pub struct UsageReport {
+    /// Requests per endpoint, with IDs collapsed (`/user/{id}/profile`).
+    pub requests_by_endpoint: HashMap<String, u64>,
+    /// Requests per (redacted) API key.
+    pub requests_by_key: HashMap<String, u64>,
+    /// API error counts by Torn error code.
+    pub errors_by_code: HashMap<u16, u64>,
+    /// Lookups served from the client's one-time caches (catalogs,
+    /// capabilities, static data).
+    pub cache_hits: u64,
+    /// Total time spent waiting on rate limiter slots.
+    pub rate_limit_wait: Duration,
+}
+
+impl UsageReport {
+    /// Total requests sent, across all endpoints.
+    pub fn total_requests(&self) -> u64 {
+        self.requests_by_endpoint.values().sum()
+    }
+}
+
+/// Per-key counters returned by [`crate::TornClient::key_stats`], keyed by
+/// the redacted key form used throughout logs and reports. Makes uneven
+/// failure distributions visible — e.g. one donated key in a pool of five
+/// answering code 16 half the time.
+#[derive(Debug, Clone, Default)]
+pub struct KeyStats {
+    /// Requests sent with this key.
+    pub requests: u64,
+    /// API error counts by Torn error code.
+    pub errors_by_code: HashMap<u16, u64>,
+    /// Transport-level failures (connect, TLS, body read).
+    pub transport_failures: u64,
+    /// Wall-clock time of the key's most recent request.
+    pub last_used: Option<SystemTime>,
+}
+
+impl KeyStats {
+    /// Fraction of this key's requests that ended in an API error.
+    pub fn error_rate(&self) -> f64 {
+        if self.requests == 0 {
+            return 0.0;
+        }
+        let errors: u64 = self.errors_by_code.values().sum();
+        errors as f64 / self.requests as f64
+    }
+}
+
+/// Counters shared by all clones of a client.
+#[derive(Debug, Default)]
+pub(crate) struct UsageTracker {
+    by_endpoint: Mutex<HashMap<String, u64>>,
+    by_key: Mutex<HashMap<String, u64>>,
+    errors_by_code: Mutex<HashMap<u16, u64>>,
+    cache_hits: AtomicU64,
+    rate_limit_wait_micros: AtomicU64,
+    key_stats: Mutex<HashMap<String, KeyStats>>,
+}
+
+impl UsageTracker {
+    pub(crate) fn record_request(&self, url: &str, redacted_key: &str) {
+        let endpoint = endpoint_label(url);
+        *self
+            .by_endpoint
+            .lock()
+            .expect("usage tracker poisoned")
+            .entry(endpoint)
+            .or_default() += 1;
+        *self
+            .by_key
+            .lock()
+            .expect("usage tracker poisoned")
+            .entry(redacted_key.to_owned())
+            .or_default() += 1;
+        let mut stats = self.key_stats.lock().expect("usage tracker poisoned");
+        let entry = stats.entry(redacted_key.to_owned()).or_default();
+        entry.requests += 1;
+        entry.last_used = Some(SystemTime::now());
+    }
+
+    pub(crate) fn record_key_api_error(&self, redacted_key: &str, code: u16) {
+        *self
+            .key_stats
+            .lock()
+            .expect("usage tracker poisoned")
+            .entry(redacted_key.to_owned())
+            .or_default()
+            .errors_by_code
+            .entry(code)
+            .or_default() += 1;
+    }
+
+    pub(crate) fn record_key_transport_failure(&self, redacted_key: &str) {
+        self.key_stats
+            .lock()
+            .expect("usage tracker poisoned")
+            .entry(redacted_key.to_owned())
+            .or_default()
+            .transport_failures += 1;
+    }
+
+    pub(crate) fn key_stats(&self) -> HashMap<String, KeyStats> {
+        self.key_stats
+            .lock()
+            .expect("usage tracker poisoned")
+            .clone()
+    }
+
+    pub(crate) fn record_api_error(&self, code: u16) {
+        *self
+            .errors_by_code
+            .lock()
+            .expect("usage tracker poisoned")
+            .entry(code)
+            .or_default() += 1;
+    }
+
+    pub(crate) fn record_cache_hit(&self) {
+        self.cache_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_rate_limit_wait(&self, wait: Duration) {
+        self.rate_limit_wait_micros
+            .fetch_add(wait.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn report(&self) -> UsageReport {
+        UsageReport {
+            requests_by_endpoint: self
+                .by_endpoint
+                .lock()
+                .expect("usage tracker poisoned")
+                .clone(),
+            requests_by_key: self.by_key.lock().expect("usage tracker poisoned").clone(),
+            errors_by_code: self
+                .errors_by_code
+                .lock()
+                .expect("usage tracker poisoned")
+                .clone(),
+            cache_hits: self.cache_hits.load(Ordering::Relaxed),
+            rate_limit_wait: Duration::from_micros(
+                self.rate_limit_wait_micros.load(Ordering::Relaxed),
+            ),
+        }
+    }
+}
+
+/// Normalizes a request URL into an endpoint label: base URL and query
+/// stripped, numeric path segments collapsed to `{id}`.
+fn endpoint_label(url: &str) -> String {
+    let without_query = url.split('?').next().unwrap_or(url);
+    let path = without_query
+        .find("/v2/")
+        .map(|at| &without_query[at + 3..])
+        .unwrap_or(without_query);
+    path.split('/')
+        .map(|segment| {
+            if !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoint_labels_collapse_ids_and_strip_noise() {
+        assert_eq!(
+            endpoint_label("https://api.torn.com/v2/user/2503189/profile?limit=5"),
+            "/user/{id}/profile"
+        );
+        assert_eq!(
+            endpoint_label("https://api.torn.com/v2/faction/attacks"),
+            "/faction/attacks"
+        );
+    }
+
+    #[test]
+    fn key_stats_attribute_failures_to_their_key() {
+        let tracker = UsageTracker::default();
+        tracker.record_request("https://api.torn.com/v2/user/profile", "good***");
+        tracker.record_request("https://api.torn.com/v2/user/profile", "flaky***");
+        tracker.record_request("https://api.torn.com/v2/user/profile", "flaky***");
+        tracker.record_key_api_error("flaky***", 16);
+        tracker.record_key_transport_failure("good***");
+
+        let stats = tracker.key_stats();
+        assert_eq!(stats["flaky***"].requests, 2);
+        assert_eq!(stats["flaky***"].errors_by_code[&16], 1);
+        assert_eq!(stats["flaky***"].error_rate(), 0.5);
+        assert_eq!(stats["good***"].transport_failures, 1);
+        assert_eq!(stats["good***"].error_rate(), 0.0);
+        assert!(stats["good***"].last_used.is_some());
+    }
+
+    #[test]
+    fn report_aggregates_counters() {
+        let tracker = UsageTracker::default();
+        tracker.record_request("https://api.torn.com/v2/user/profile", "abcd***");
+        tracker.record_request("https://api.torn.com/v2/user/profile", "abcd***");
+        tracker.record_api_error(5);
+        tracker.record_cache_hit();
+        tracker.record_rate_limit_wait(Duration::from_millis(30));
+
+        let report = tracker.report();
+        assert_eq!(report.total_requests(), 2);
+        assert_eq!(report.requests_by_endpoint["/user/profile"], 2);
+        assert_eq!(report.requests_by_key["abcd***"], 2);
+        assert_eq!(report.errors_by_code[&5], 1);
+        assert_eq!(report.cache_hits, 1);
+        assert!(report.rate_limit_wait >= Duration::from_millis(30));
+    }
+}
@@ -0,0 +1,71 @@
+//! Legacy v1 API access.
+//!
+//! Many selections still only exist on API v1
+//! (`https://api.torn.com/user/?selections=...`), which authenticates with a
+//! `key=` query parameter instead of v2's `Authorization` header and keeps
+//! its payloads keyed by selection. [`V1Endpoint`] is a thin layer over the
+//! same request pipeline — key rotation, rate limiting, retries and health
+//! tracking all apply — returning raw JSON (or a caller-supplied type) since
+//! v1 shapes vary per selection combination.
+
+use serde::de::DeserializeOwned;
+
+use crate::client::{KeyAuth, TornClient};
+use crate::Result;
+
+/// Handle for the legacy v1 API, obtained from [`TornClient::v1`].
+#[derive(Clone)]
+pub struct V1Endpoint {
+    client: TornClient,
+}
+
+impl V1Endpoint {
+    pub(crate) fn new(client: TornClient) -> Self {
+        Self { client }
+    }
+
+    /// `GET /{section}/{id}?selections=...` against the v1 origin,
+    /// deserializing into `T`. Pass `None` for `id` to query the key owner.
+    ///
+    /// ```no_run
+    /// # async fn run(client: torn_client::TornClient) -> torn_client::Result<()> {
+    /// let bars = client
+    ///     .v1()
+    ///     .get_raw("user", None, &["bars", "icons"])
+    ///     .await?;
+    /// println!("{}", bars["energy"]["current"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get<T: DeserializeOwned>(
+        &self,
+        section: &str,
+        id: Option<u64>,
+        selections: &[&str],
+    ) -> Result<T> {
+        let base = self.client.v1_base_url();
+        let url = match id {
+            Some(id) => format!("{base}/{section}/{id}"),
+            None => format!("{base}/{section}/"),
+        };
+        let query = vec![("selections".to_owned(), selections.join(","))];
+        self.client
+            .get_url_auth(
+                &url,
+                &query,
+                KeyAuth::QueryParam,
+                &crate::client::RequestOptions::default(),
+            )
+            .await
+    }
+
+    /// Like [`V1Endpoint::get`] but returns the raw JSON body.
+    pub async fn get_raw(
+        &self,
+        section: &str,
+        id: Option<u64>,
+        selections: &[&str],
+    ) -> Result<serde_json::Value> {
+        self.get(section, id, selections).await
+    }
+}
@@ -0,0 +1,190 @@
+//! Runtime response validation against the bundled OpenAPI spec.
+//!
+//! Compiled only with the `validate-responses` dev feature. Every response
+//! body is checked against the matching schema in `openapi/latest.json`;
+//! mismatches (unexpected fields, wrong types, missing required fields) are
+//! logged through `tracing` so model drift shows up in staging before it
+//! becomes an opaque deserialization error in production. Validation never
+//! fails a request — it only reports.
+
+use std::sync::OnceLock;
+
+use serde_json::Value;
+
+static SPEC: OnceLock<Value> = OnceLock::new();
+
+fn spec() -> &'static Value {
+    SPEC.get_or_init(|| {
+        serde_json::from_str(include_str!("../openapi/latest.json"))
+            .expect("bundled openapi spec is valid json")
+    })
+}
+
+/// Validates a raw response body against the spec schema for `url`, logging
+/// one warning per mismatch. Unknown paths and non-JSON bodies are skipped.
+pub(crate) fn validate_body(url: &str, body: &[u8]) {
+    let Ok(value) = serde_json::from_slice::<Value>(body) else {
+        return;
+    };
+    // Error envelopes have their own shape and are reported elsewhere.
+    if value.get("error").is_some() {
+        return;
+    }
+    let path = url_path(url);
+    let Some(schema) = schema_for_path(path) else {
+        return;
+    };
+    let mut issues = Vec::new();
+    check(&value, schema, path, &mut issues);
+    for issue in issues {
+        tracing::warn!(url = path, issue, "response does not match openapi spec");
+    }
+}
+
+/// The request path with base URL and query stripped.
+fn url_path(url: &str) -> &str {
+    let without_query = url.split('?').next().unwrap_or(url);
+    without_query
+        .find("/v2/")
+        .map(|at| &without_query[at + 3..])
+        .unwrap_or(without_query)
+}
+
+/// Finds the 200-response schema whose path template matches `path`;
+/// `{placeholder}` segments match any single segment.
+fn schema_for_path(path: &str) -> Option<&'static Value> {
+    let paths = spec().get("paths")?.as_object()?;
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    for (template, item) in paths {
+        let template_segments: Vec<&str> = template.trim_matches('/').split('/').collect();
+        if template_segments.len() != segments.len() {
+            continue;
+        }
+        let matches = template_segments
+            .iter()
+            .zip(&segments)
+            .all(|(pattern, actual)| pattern.starts_with('{') || pattern == actual);
+        if matches {
+            return item.pointer("/get/responses/200/content/application~1json/schema");
+        }
+    }
+    None
+}
+
+/// Resolves a `#/components/schemas/...` reference inside the bundled spec.
+fn resolve(schema: &Value) -> &Value {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        if let Some(target) = spec().pointer(&reference[1..].replace("~1", "/")) {
+            return target;
+        }
+    }
+    schema
+}
+
+/// Recursively checks `value` against `schema`, appending one human-readable
+/// line per mismatch.
+fn check(value: &Value, schema: &Value, location: &str, issues: &mut Vec<String>) {
+    let schema = resolve(schema);
+    let Some(expected) = schema.get("type").and_then(Value::as_str) else {
+        return;
+    };
+    match expected {
+        "object" => {
+            let Some(map) = value.as_object() else {
+                issues.push(format!("{location}: expected object, got {}", kind(value)));
+                return;
+            };
+            let properties = schema.get("properties").and_then(Value::as_object);
+            if let Some(required) = schema.get("required").and_then(Value::as_array) {
+                for name in required.iter().filter_map(Value::as_str) {
+                    if !map.contains_key(name) {
+                        issues.push(format!("{location}.{name}: required field missing"));
+                    }
+                }
+            }
+            let Some(properties) = properties else {
+                return;
+            };
+            for (name, field) in map {
+                match properties.get(name) {
+                    Some(field_schema) if !field.is_null() => {
+                        check(field, field_schema, &format!("{location}.{name}"), issues);
+                    }
+                    Some(_) => {}
+                    None => issues.push(format!("{location}.{name}: unexpected field")),
+                }
+            }
+        }
+        "array" => {
+            let Some(entries) = value.as_array() else {
+                issues.push(format!("{location}: expected array, got {}", kind(value)));
+                return;
+            };
+            if let Some(items) = schema.get("items") {
+                for (index, entry) in entries.iter().enumerate() {
+                    check(entry, items, &format!("{location}[{index}]"), issues);
+                }
+            }
+        }
+        "string" if !value.is_string() => {
+            issues.push(format!("{location}: expected string, got {}", kind(value)));
+        }
+        "integer" if !value.is_i64() && !value.is_u64() => {
+            issues.push(format!("{location}: expected integer, got {}", kind(value)));
+        }
+        "number" if !value.is_number() => {
+            issues.push(format!("{location}: expected number, got {}", kind(value)));
+        }
+        "boolean" if !value.is_boolean() => {
+            issues.push(format!("{location}: expected boolean, got {}", kind(value)));
+        }
+        _ => {}
+    }
+}
+
+fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issues_for(path: &str, body: &str) -> Vec<String> {
+        let value: Value = serde_json::from_str(body).unwrap();
+        let schema = schema_for_path(path).expect("path in spec");
+        let mut issues = Vec::new();
+        check(&value, schema, path, &mut issues);
+        issues
+    }
+
+    #[test]
+    fn conforming_body_passes() {
+        let body = r#"{"timestamp": 1700000000}"#;
+        assert!(issues_for("/torn/timestamp", body).is_empty());
+    }
+
+    #[test]
+    fn drifted_body_reports_each_mismatch() {
+        let body = r#"{"timestamp": "soon", "server": "api-3"}"#;
+        let issues = issues_for("/torn/timestamp", body);
+        assert!(issues.iter().any(|i| i.contains("expected integer")));
+        assert!(issues.iter().any(|i| i.contains("unexpected field")));
+    }
+
+    #[test]
+    fn templated_paths_match_concrete_ids() {
+        let body = r#"{"profile": {"name": "X", "level": 10}}"#;
+        let issues = issues_for("/user/2503189/profile", body);
+        assert!(issues
+            .iter()
+            .any(|i| i.contains("player_id") && i.contains("required")));
+    }
+}